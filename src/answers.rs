@@ -0,0 +1,77 @@
+use crate::registry::DayId;
+use crate::vault;
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Accepted answers of one day, stored as `<part>: <answer>` lines in
+/// `answers/<year>/<day>.txt`, or only encrypted next to it (see `vault`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    path: PathBuf,
+    parts: BTreeMap<u8, String>,
+}
+
+impl Answers {
+    /// Loads the answers of `id`; a day without a file has no answers yet.
+    pub fn load(id: DayId) -> Result<Self> {
+        let path = id.answers_path();
+        let parts = match vault::read(&path)? {
+            Some(bytes) => parse(&String::from_utf8_lossy(&bytes))
+                .with_context(|| format!("in {}", path.display()))?,
+            None => BTreeMap::new(),
+        };
+        Ok(Self { path, parts })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts.get(&part).map(String::as_str)
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        self.parts.insert(part, answer.to_owned());
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let text = self
+            .parts
+            .iter()
+            .fold(String::new(), |mut output, (part, answer)| {
+                let _ = writeln!(output, "{}: {}", part, answer);
+                output
+            });
+        vault::write(&self.path, text.as_bytes())
+    }
+}
+
+fn parse(text: &str) -> Result<BTreeMap<u8, String>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let (part, answer) = line
+                .split_once(": ")
+                .ok_or_else(|| anyhow!("line {}: expected `<part>: <answer>`", i + 1))?;
+            let part = part
+                .parse()
+                .with_context(|| format!("line {}: bad part `{}`", i + 1, part))?;
+            Ok((part, answer.to_owned()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("1: 11048\n2: 4,6,3,5\n\n").unwrap(),
+            BTreeMap::from([(1, String::from("11048")), (2, String::from("4,6,3,5"))])
+        );
+        assert!(parse("1 11048").is_err());
+        assert!(parse("one: 11048").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! Day 1: Historian Hysteria
+//!
+//! Techniques: sorting, counting
+//! Complexity: O(n log n), O(n)
+
+use adv_code::*;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+
+const YEAR: &str = "2024";
+const DAY: &str = "01";
+
+const TEST: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+type Parsed = (Vec<usize>, Vec<usize>);
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    runner.synthetic(generate);
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1((left, right): &Parsed) -> Result<usize> {
+        let (mut left, mut right) = (left.clone(), right.clone());
+        left.sort();
+        right.sort();
+        let answer: usize = left
+            .into_iter()
+            .zip(right)
+            .map(|(l, r)| l.abs_diff(r))
+            .sum();
+        Ok(answer)
+    }
+
+    assert_eq!(11, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2((left, right): &Parsed) -> Result<usize> {
+        let mut counts = HashMap::new();
+        for &e in right {
+            *counts.entry(e).or_insert(0) += 1;
+        }
+        let answer: usize = left.iter().map(|e| e * counts.get(e).unwrap_or(&0)).sum();
+        Ok(answer)
+    }
+
+    assert_eq!(31, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+/// `n` pairs of location ids, with enough repeats for part 2 to count.
+fn generate(n: usize) -> String {
+    let mut rng = profile::Rng::new(n as u64);
+    let mut input = String::new();
+    for _ in 0..n {
+        let (left, right) = (rng.range(10000..99999), rng.range(10000..10100));
+        input += &format!("{}   {}\n", left, right);
+    }
+    input
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    Ok(reader
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            (
+                parts.next().unwrap().parse::<usize>().unwrap(),
+                parts.next().unwrap().parse::<usize>().unwrap(),
+            )
+        })
+        .unzip())
+}
@@ -1,13 +1,15 @@
-use adv_code_2024::*;
+//! Day 2: Red-Nosed Reports
+//!
+//! Techniques: sliding window, brute force, data parallelism
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
+use util::par;
 
+const YEAR: &str = "2024";
 const DAY: &str = "02";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 7 6 4 2 1
@@ -18,73 +20,69 @@ const TEST: &str = "\
 1 3 6 7 9
 ";
 
+type Parsed = Vec<Vec<i32>>;
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    let reports = reader
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| {
+            line.split_whitespace()
+                .flat_map(|i| i.parse::<i32>())
+                .collect()
+        })
+        .collect();
+    Ok(reports)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let answer: usize = reader
-            .lines()
-            .map_while(Result::ok)
-            .map(|line| {
-                let mut iter = line
-                    .split_whitespace()
-                    .flat_map(|i| i.parse::<i32>())
-                    .multipeek();
-
-                while let Some(cur) = iter.next() {
-                    if let Some(&n0) = iter.peek() {
-                        if let Some(&n1) = iter.peek() {
-                            if is_safe_with_signum(cur, n0, n1).is_none() {
-                                return false;
-                            }
+    fn part1(input: &Parsed) -> Result<usize> {
+        let answer: usize = par::map(input, |row| {
+            let mut iter = row.iter().copied().multipeek();
+
+            while let Some(cur) = iter.next() {
+                if let Some(&n0) = iter.peek() {
+                    if let Some(&n1) = iter.peek() {
+                        if is_safe_with_signum(cur, n0, n1).is_none() {
+                            return false;
                         }
                     }
                 }
-                true
-            })
-            .filter(|&a| a)
-            .count();
+            }
+            true
+        })
+        .into_iter()
+        .filter(|&a| a)
+        .count();
 
         Ok(answer)
     }
 
-    assert_eq!(2, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(2, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let answer: usize = reader
-            .lines()
-            .map_while(Result::ok)
-            .map(|line| {
-                let mut row = line
-                    .split_whitespace()
-                    .flat_map(|i| i.parse::<i32>())
-                    .collect::<Vec<i32>>();
-                part2_is_safe(&mut row)
-            })
+    fn part2(input: &Parsed) -> Result<usize> {
+        let answer: usize = par::map(input, |row| part2_is_safe(&mut row.clone()))
+            .into_iter()
             .filter(|&a| a)
             .count();
         Ok(answer)
     }
 
-    assert_eq!(4, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(4, part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
 
 fn part2_is_safe(row: &mut [i32]) -> bool {
@@ -1,17 +1,18 @@
-use adv_code_2024::*;
+//! Day 3: Mull It Over
+//!
+//! Techniques: nom, parsing
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use nom::bytes::complete::{is_not, tag, take_until};
 use nom::character::complete::u32;
 use nom::combinator::{opt, value};
 use nom::sequence::{delimited, separated_pair, tuple};
 use nom::IResult;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
 
+const YEAR: &str = "2024";
 const DAY: &str = "03";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
@@ -21,50 +22,43 @@ const TEST2: &str = "\
 xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
 ";
 
+type Parsed = String;
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader
-            .read_to_string(&mut input)
-            .expect("cannot read string");
-        let (_, answer) = parse(input.as_str()).expect("");
+    fn part1(input: &Parsed) -> Result<usize> {
+        let (_, answer) = sum_muls(input).expect("");
 
         Ok(answer)
     }
 
-    assert_eq!(161, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(161, part1(&parse(BufReader::new(TEST.as_bytes()))?)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader
-            .read_to_string(&mut input)
-            .expect("cannot read string");
-        let (_, answer) = parse2(input.as_str()).expect("");
+    fn part2(input: &Parsed) -> Result<usize> {
+        let (_, answer) = sum_enabled_muls(input).expect("");
 
         Ok(answer)
     }
 
-    assert_eq!(48, part2(BufReader::new(TEST2.as_bytes()))?);
+    assert_eq!(48, part2(&parse(BufReader::new(TEST2.as_bytes()))?)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
 
 #[derive(Debug, PartialEq)]
@@ -106,7 +100,7 @@ fn mul(input: &str) -> IResult<&str, Option<usize>> {
     Result::Ok((&input[1..], None))
 }
 
-fn parse(input: &str) -> IResult<&str, usize> {
+fn sum_muls(input: &str) -> IResult<&str, usize> {
     let mut out: usize = 0;
     let mut rem: &str = input;
     loop {
@@ -123,7 +117,7 @@ fn parse(input: &str) -> IResult<&str, usize> {
     Result::Ok(("", out))
 }
 
-fn parse2(input: &str) -> IResult<&str, usize> {
+fn sum_enabled_muls(input: &str) -> IResult<&str, usize> {
     let mut out: usize = 0;
     let mut rem: &str = input;
     let mut suppress = false;
@@ -178,6 +172,6 @@ mod tests {
 
     #[test]
     fn test_parser() {
-        assert_eq!(parse(TEST), Result::Ok(("", 161)));
+        assert_eq!(sum_muls(TEST), Result::Ok(("", 161)));
     }
 }
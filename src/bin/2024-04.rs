@@ -1,13 +1,15 @@
-use adv_code_2024::*;
+//! Day 4: Ceres Search
+//!
+//! Techniques: grid, word search, grid transforms
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
+use util::grid::{Direction, Vec2};
 
+const YEAR: &str = "2024";
 const DAY: &str = "04";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 MMMSXXMASM
@@ -62,30 +64,15 @@ impl Grid {
     }
 
     fn count_mas(&self, r: usize, c: usize) -> usize {
-        let directions: [(i32, i32); 8] = [
-            (0, -1),
-            (-1, 0),
-            (0, 1),
-            (1, 0),
-            (1, 1),
-            (-1, 1),
-            (1, -1),
-            (-1, -1),
-        ];
         let target = b"MAS";
-        directions
+        Direction::ALL
             .iter()
-            .filter(|(dy, dx)| {
+            .filter(|&&d| {
                 target.iter().enumerate().all(|(i, &byte)| {
-                    let (rr, cc) = (
-                        (r as i32 + dy * (i as i32 + 1)),
-                        (c as i32 + dx * (i as i32 + 1)),
-                    );
-                    rr >= 0
-                        && rr < self.rows as i32
-                        && cc >= 0
-                        && cc < self.rows as i32
-                        && self.data[rr as usize][cc as usize] == byte
+                    let cell = Vec2::from((r, c)) + Vec2::from(d) * (i as isize + 1);
+                    cell.to_point().is_some_and(|(rr, cc)| {
+                        rr < self.rows && cc < self.rows && self.data[rr][cc] == byte
+                    })
                 })
             })
             .count()
@@ -119,42 +106,66 @@ impl Grid {
     }
 }
 
+fn parse<R: BufRead>(reader: R) -> Result<Grid> {
+    Ok(Grid::new(reader))
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::new(reader);
-
+    fn part1(grid: &Grid) -> Result<usize> {
         let answer = grid.count_xmax();
         Ok(answer)
     }
 
-    assert_eq!(18, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(18, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::new(reader);
-
+    fn part2(grid: &Grid) -> Result<usize> {
         let answer = grid.count_x_max();
         Ok(answer)
     }
 
-    assert_eq!(9, part2(BufReader::new(TEST.as_bytes()))?);
+    /// Matches every 3x3 window against the four turns of the X-MAS shape.
+    fn part2_windows(grid: &Grid) -> Result<usize> {
+        let cells = util::grid::Grid::new(grid.data.concat(), grid.rows, grid.cols);
+        let shape = util::grid::Grid::<u8>::from_reader("M.S\n.A.\nM.S\n".as_bytes())?;
+        let shape = shape.view();
+        let shapes = [
+            shape,
+            shape.rotate_right(),
+            shape.rotate_180(),
+            shape.rotate_left(),
+        ];
+        let answer = (0..grid.rows.saturating_sub(2))
+            .cartesian_product(0..grid.cols.saturating_sub(2))
+            .filter(|&top_left| {
+                let window = cells.view().sub_grid(top_left, 3, 3);
+                shapes.iter().any(|shape| {
+                    window
+                        .iter()
+                        .zip(shape.iter())
+                        .all(|(cell, wanted)| *wanted == b'.' || cell == wanted)
+                })
+            })
+            .count();
+        Ok(answer)
+    }
+
+    const PART2: &Variants<Grid, usize> = &[("index math", part2), ("windows", part2_windows)];
+
+    for (_, part2) in PART2 {
+        assert_eq!(9, part2(&test)?);
+    }
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_variants(2, &input, PART2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
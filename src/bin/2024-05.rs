@@ -0,0 +1,125 @@
+//! Day 5: Print Queue
+//!
+//! Techniques: custom ordering, sorting
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader};
+use util::parse::blocks_n;
+
+const YEAR: &str = "2024";
+const DAY: &str = "05";
+
+const TEST: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+type Parsed = (HashSet<(usize, usize)>, Vec<Vec<usize>>);
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1((rules, orders): &Parsed) -> Result<usize> {
+        let answer = orders
+            .iter()
+            .filter(|&o| is_order_correct(o, rules))
+            .map(|o| o[o.len() / 2])
+            .sum();
+
+        Ok(answer)
+    }
+
+    assert_eq!(143, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2((rules, orders): &Parsed) -> Result<usize> {
+        let answer = orders
+            .iter()
+            .filter(|&o| !is_order_correct(o, rules))
+            .map(|o| {
+                let mut oo = o.to_owned();
+                oo.sort_by(|a, b| {
+                    if rules.contains(&(*a, *b)) {
+                        return Ordering::Less;
+                    }
+                    Ordering::Greater
+                });
+                oo[oo.len() / 2]
+            })
+            .sum();
+
+        Ok(answer)
+    }
+
+    assert_eq!(123, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let [rules, updates] = blocks_n(&input)?;
+    let rules = rules
+        .lines()
+        .map(|line| {
+            let (before, after) = line.split_once("|")?;
+            Ok((before.parse()?, after.parse()?))
+        })
+        .collect::<Result<_>>()?;
+    let updates = updates
+        .lines()
+        .map(|line| line.comma_list())
+        .try_collect()?;
+    Ok((rules, updates))
+}
+
+fn is_order_correct(order: &[usize], rules: &HashSet<(usize, usize)>) -> bool {
+    for (i, p) in order[..order.len() - 1].iter().enumerate() {
+        for e in &order[i + 1..] {
+            if !rules.contains(&(*p, *e)) {
+                return false;
+            }
+        }
+    }
+    true
+}
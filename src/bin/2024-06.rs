@@ -0,0 +1,215 @@
+//! Day 6: Guard Gallivant
+//!
+//! Techniques: grid, simulation, cycle detection, data parallelism
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader};
+use tracing::debug;
+use util::grid::{Direction, FromCell, Grid, Point, Vec2};
+use util::par;
+use util::parse::Span;
+use viz::{Palette, Recorder, BLACK, GREY, ORANGE, RED};
+
+const YEAR: &str = "2024";
+const DAY: &str = "06";
+
+const TEST: &str = "\
+...........#.....#......
+...................#....
+...#.....##.............
+......................#.
+..................#.....
+..#.....................
+....................#...
+........................
+.#........^.............
+..........#..........#..
+..#.....#..........#....
+........#.....#..#......
+";
+
+/// The lab and where the guard starts.
+type Parsed = (Grid<u8>, Point);
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let (grid, [start]) = Grid::parse_marked(Span::new(&input), ['^'], u8::from_cell)?;
+    Ok((grid, start))
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1((grid, start): &Parsed) -> Result<usize> {
+        let mut seen: HashSet<Point> = HashSet::new();
+        let palette = Palette::new(BLACK).with(b'#', GREY);
+        let mut recorder = Recorder::new("patrol", palette).every(10);
+
+        let mut pos = *start;
+        let mut direction = Direction::North;
+        loop {
+            seen.insert(pos);
+            recorder.record_with(grid, seen.iter().map(|&p| (p, ORANGE)).chain([(pos, RED)]));
+            let Some(next) = grid.step(&pos, direction) else {
+                break;
+            };
+            if grid.get(&next) == b'#' {
+                direction = direction.turn_right();
+            } else {
+                pos = next;
+            }
+        }
+        recorder.snapshot_with(grid, seen.iter().map(|&p| (p, ORANGE)));
+        recorder.finish()?;
+        Ok(seen.len())
+    }
+
+    assert_eq!(91, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    /// Walks the guard again with an obstacle on each cell of its path.
+    fn part2((grid, start): &Parsed) -> Result<usize> {
+        let candidates = candidates(grid, *start)?;
+        let answer = par::map(&candidates, |&obstacle| {
+            patrol(grid, *start, Some(obstacle)).is_none()
+        })
+        .into_iter()
+        .filter(|&cycle| cycle)
+        .count();
+        Ok(answer)
+    }
+
+    /// Jumps from turn to turn with a table of where each straight walk
+    /// stops, cutting a walk short where the new obstacle is in its way.
+    fn part2_jumps((grid, start): &Parsed) -> Result<usize> {
+        let candidates = candidates(grid, *start)?;
+        let jumps = Jumps::new(grid);
+        let answer = par::map(&candidates, |&obstacle| jumps.loops(*start, obstacle))
+            .into_iter()
+            .filter(|&cycle| cycle)
+            .count();
+        Ok(answer)
+    }
+
+    const PART2: &Variants<Parsed, usize> = &[("walk", part2), ("jumps", part2_jumps)];
+
+    for (_, part2) in PART2 {
+        assert_eq!(19, part2(&test)?);
+    }
+
+    runner.solve_variants(2, &input, PART2);
+    //endregion
+
+    runner.finish()
+}
+
+/// Where a new obstacle can go: every cell of the guard's path but the
+/// start.
+fn candidates(grid: &Grid<u8>, start: Point) -> Result<Vec<Point>> {
+    let path =
+        patrol(grid, start, None).ok_or_else(|| anyhow!("the guard walks in a loop already"))?;
+    let candidates = path.into_iter().filter(|&p| p != start).collect_vec();
+    debug!(candidates = candidates.len(), "walked guard path");
+    Ok(candidates)
+}
+
+/// The cells the guard visits from `start` until it leaves the grid, with
+/// an extra obstacle if any; `None` if it walks in a loop instead.
+fn patrol(grid: &Grid<u8>, start: Point, obstacle: Option<Point>) -> Option<Vec<Point>> {
+    // The directions each cell has been left in, one bit each.
+    let mut seen = vec![0u8; grid.height * grid.width];
+    let mut pos = start;
+    let mut direction = Direction::North;
+    loop {
+        let cell = &mut seen[pos.0 * grid.width + pos.1];
+        if *cell & 1 << direction as u8 != 0 {
+            return None;
+        }
+        *cell |= 1 << direction as u8;
+        let Some(next) = grid.step(&pos, direction) else {
+            break;
+        };
+        if grid.get(&next) == b'#' || obstacle == Some(next) {
+            direction = direction.turn_right();
+        } else {
+            pos = next;
+        }
+    }
+    let visited = (0..grid.height)
+        .cartesian_product(0..grid.width)
+        .filter(|&(r, c)| seen[r * grid.width + c] != 0)
+        .collect();
+    Some(visited)
+}
+
+/// Where the guard stops in front of the next obstacle, from each cell and
+/// orthogonal direction; `None` where it walks off the grid instead.
+struct Jumps {
+    stops: Vec<[Option<Point>; 4]>,
+    width: usize,
+}
+
+impl Jumps {
+    fn new(grid: &Grid<u8>) -> Self {
+        let cells = grid.array.len();
+        let mut stops = vec![[None; 4]; cells];
+        for direction in Direction::ORTHOGONAL {
+            let d = direction as usize / 2;
+            // Visit the cell ahead first, so that its stop is known.
+            let forwards = matches!(direction, Direction::North | Direction::West);
+            for k in 0..cells {
+                let i = if forwards { k } else { cells - 1 - k };
+                let pt = grid.map_index(i);
+                stops[i][d] = match grid.step(&pt, direction) {
+                    None => None,
+                    Some(next) if grid.get(&next) == b'#' => Some(pt),
+                    Some(next) => stops[next.0 * grid.width + next.1][d],
+                };
+            }
+        }
+        Self {
+            stops,
+            width: grid.width,
+        }
+    }
+
+    /// Where the guard stops walking from `pt` in `direction`, with an extra
+    /// `obstacle` that may come before the table's stop.
+    fn stop(&self, pt: Point, direction: Direction, obstacle: Point) -> Option<Point> {
+        let stop = self.stops[pt.0 * self.width + pt.1][direction as usize / 2];
+        let (from, step) = (Vec2::from(pt), Vec2::from(direction));
+        let ahead = Vec2::from(obstacle) - from;
+        let distance = ahead.manhattan();
+        let blocks = distance > 0
+            && ahead == step * distance as isize
+            && stop.is_none_or(|s| (Vec2::from(s) - from).manhattan() >= distance);
+        if blocks {
+            (from + step * (distance as isize - 1)).to_point()
+        } else {
+            stop
+        }
+    }
+
+    /// Whether the guard walks in a loop from `start` with `obstacle` added.
+    fn loops(&self, start: Point, obstacle: Point) -> bool {
+        let mut turns = HashSet::new();
+        let (mut pos, mut direction) = (start, Direction::North);
+        while let Some(stop) = self.stop(pos, direction, obstacle) {
+            if !turns.insert((stop, direction)) {
+                return true;
+            }
+            (pos, direction) = (stop, direction.turn_right());
+        }
+        false
+    }
+}
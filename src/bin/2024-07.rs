@@ -0,0 +1,130 @@
+//! Day 7: Bridge Repair
+//!
+//! Techniques: recursion, brute force, data parallelism
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use radix_fmt::radix_3;
+use std::io::{BufRead, BufReader};
+use tracing::trace;
+use util::num::concat;
+use util::par;
+
+const YEAR: &str = "2024";
+const DAY: &str = "07";
+
+const TEST: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+/// Each equation as its test value and its numbers.
+type Parsed = Vec<(usize, Vec<usize>)>;
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    let equations = reader
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| {
+            let eq = line.split(':').collect_vec();
+            assert_eq!(eq.len(), 2);
+            let y = eq[0].parse::<usize>().unwrap();
+            let nums = eq[1]
+                .trim()
+                .split(" ")
+                .map(|s| s.parse::<usize>().unwrap())
+                .collect_vec();
+            (y, nums)
+        })
+        .collect();
+    Ok(equations)
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(input: &Parsed) -> Result<usize> {
+        let answer = par::map(input, |&(y, ref op)| {
+            for value in 0..2u32.pow(op.len() as u32) {
+                // let mut bits = mask & value;
+                let result = op[1..].iter().enumerate().fold(op[0], |acc, (i, &e)| {
+                    if (value >> i) & 1 == 0 {
+                        acc + e
+                    } else {
+                        acc * e
+                    }
+                });
+                if y == result {
+                    return Some(y);
+                }
+            }
+            None
+        })
+        .into_iter()
+        .flatten()
+        .sum();
+        Ok(answer)
+    }
+
+    assert_eq!(3749, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(input: &Parsed) -> Result<usize> {
+        let answer = par::map(input, |&(y, ref nums)| {
+            for t in 0..3u32.pow(nums.len() as u32) {
+                let ops = format!("{:0>20}", radix_3(t).to_string())
+                    .chars()
+                    .rev()
+                    .collect_vec();
+
+                let result = nums[1..]
+                    .iter()
+                    .enumerate()
+                    .try_fold(nums[0], |acc, (i, &n)| {
+                        if acc > y {
+                            return None;
+                        }
+                        if ops[i] == '0' {
+                            acc.checked_add(n)
+                        } else if ops[i] == '1' {
+                            acc.checked_mul(n)
+                        } else {
+                            concat(acc, n)
+                        }
+                    });
+                if let Some(val) = result {
+                    if y == val {
+                        trace!(y, "calibrated");
+                        return Some(y);
+                    }
+                }
+            }
+            None
+        })
+        .into_iter()
+        .flatten()
+        .sum();
+        Ok(answer)
+    }
+
+    assert_eq!(11387, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
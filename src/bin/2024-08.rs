@@ -0,0 +1,148 @@
+//! Day 8: Resonant Collinearity
+//!
+//! Techniques: grid, geometry
+
+use adv_code::util::grid::{Grid, Vec2};
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader};
+
+const YEAR: &str = "2024";
+const DAY: &str = "08";
+
+const TEST: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+trait P8 {
+    fn locate_antennas(&self, f: &u8) -> Vec<Vec2>;
+    fn get_frequency_types(&self) -> HashSet<&u8>;
+}
+
+impl P8 for Grid<u8> {
+    fn locate_antennas(&self, f: &u8) -> Vec<Vec2> {
+        self.array
+            .iter()
+            .enumerate()
+            .filter(|&(_, b)| b == f)
+            .map(|(i, _)| Vec2::from_index(i, self.width))
+            .collect_vec()
+    }
+
+    fn get_frequency_types(&self) -> HashSet<&u8> {
+        self.array
+            .iter()
+            .filter(|&b| b.is_ascii_alphanumeric())
+            .collect::<HashSet<_>>()
+    }
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Grid<u8>> {
+    Grid::<u8>::from_reader(reader)
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(grid: &Grid<u8>) -> Result<usize> {
+        let answer = grid
+            .get_frequency_types()
+            .into_iter()
+            .flat_map(|f| {
+                let locations = grid.locate_antennas(f);
+                locations
+                    .iter()
+                    .flat_map(|loc| {
+                        let mut hashset = HashSet::new();
+                        for &another in &locations {
+                            let antinode = *loc - (another - *loc);
+                            if another != *loc && grid.contains(&antinode) {
+                                hashset.insert(antinode);
+                            }
+                        }
+                        hashset
+                    })
+                    .collect::<HashSet<_>>()
+            })
+            .collect::<HashSet<_>>()
+            .len();
+        Ok(answer)
+    }
+
+    assert_eq!(14, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(grid: &Grid<u8>) -> Result<usize> {
+        let answer = grid
+            .get_frequency_types()
+            .into_iter()
+            .flat_map(|f| {
+                let locations = grid.locate_antennas(f);
+                locations
+                    .iter()
+                    .flat_map(|loc| {
+                        let mut hashset: HashSet<Vec2> = HashSet::from_iter(locations.clone());
+                        for &another in &locations {
+                            if another != *loc {
+                                let step = *loc - another;
+                                let mut pt = *loc + step;
+                                while grid.contains(&pt) {
+                                    hashset.insert(pt);
+                                    pt += step;
+                                }
+                            }
+                        }
+                        hashset
+                    })
+                    .collect::<HashSet<_>>()
+            })
+            .collect::<HashSet<_>>()
+            .len();
+        Ok(answer)
+    }
+
+    assert_eq!(34, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let grid = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        assert_eq!(grid.get_frequency_types(), HashSet::from([&b'0', &b'A']));
+        assert_eq!(
+            grid.locate_antennas(&b'0'),
+            [(1, 8), (2, 5), (3, 7), (4, 4)].map(Vec2::from)
+        );
+        assert_eq!(
+            grid.locate_antennas(&b'A'),
+            [(5, 6), (8, 8), (9, 9)].map(Vec2::from)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Day 9: Disk Fragmenter
+//!
+//! Techniques: two pointers, compaction, interval set
+//! Complexity: O(n), O(n log n)
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader};
+use util::intervals::IntervalSet;
+
+const YEAR: &str = "2024";
+const DAY: &str = "09";
+
+const TEST: &str = "\
+2333133121414131402
+";
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DiskMap {
+    layout: Vec<String>,
+    is_file_block: bool,
+    id: u32,
+}
+
+impl DiskMap {
+    fn new() -> Self {
+        Self {
+            layout: Vec::new(),
+            is_file_block: true,
+            id: 0,
+        }
+    }
+    fn unpack(&mut self, digit: usize) {
+        self.layout.extend({
+            if self.is_file_block {
+                let r = std::iter::repeat_n(self.id.to_string(), digit);
+                self.id += 1;
+                r
+            } else {
+                std::iter::repeat_n(String::from("."), digit)
+            }
+        });
+        self.is_file_block = !self.is_file_block;
+    }
+
+    /// Moves the last block into the leftmost free one until none is left.
+    fn defrag(&mut self) {
+        // Every block before `free` holds a file, so the search for the next
+        // free one resumes from there.
+        let mut free = 0;
+        loop {
+            while free < self.layout.len() && self.layout[free] != "." {
+                free += 1;
+            }
+            if free == self.layout.len() {
+                return;
+            }
+            let Some(e) = self.layout.pop() else {
+                return;
+            };
+            // The free block just popped may have been the one found.
+            if free < self.layout.len() {
+                self.layout[free] = e;
+            }
+        }
+    }
+
+    fn checksum(&self) -> usize {
+        self.layout
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| {
+                if e != "." {
+                    Some(e.parse::<usize>().unwrap() * i)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    /// Moves whole files, highest id first, into the leftmost free run that
+    /// fits them.
+    fn defrag2(&mut self) {
+        let mut files = Vec::new();
+        let mut free = IntervalSet::new();
+        for (id, run) in &self.layout.iter().enumerate().chunk_by(|(_, id)| *id) {
+            let run = run.map(|(i, _)| i).collect_vec();
+            let span = run[0]..run[run.len() - 1] + 1;
+            if id == "." {
+                free.insert(span);
+            } else {
+                files.push((id.clone(), span));
+            }
+        }
+
+        for (id, file) in files.into_iter().rev() {
+            let len = file.len();
+            let Some(start) = free.first_gap(len, file.start) else {
+                continue;
+            };
+            self.layout[start..start + len].fill(id);
+            self.layout[file.clone()].fill(String::from("."));
+            free.remove(start..start + len);
+            free.insert(file);
+        }
+    }
+}
+
+impl Display for DiskMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.layout.iter().map(|o| o.as_str()).collect::<String>()
+        )
+    }
+}
+
+/// A disk map of about `n` digits, ending with a file like a real one.
+fn generate(n: usize) -> String {
+    let mut rng = profile::Rng::new(n as u64);
+    let mut input = (0..n | 1)
+        .map(|i| {
+            let low = if i % 2 == 0 { 1 } else { 0 };
+            char::from(b'0' + rng.range(low..10) as u8)
+        })
+        .collect::<String>();
+    input.push('\n');
+    input
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<DiskMap> {
+    let mut disk_map = DiskMap::new();
+    for line in reader.lines().map_while(Result::ok) {
+        for c in line.chars() {
+            disk_map.unpack(c.to_digit(10).unwrap() as usize)
+        }
+    }
+    Ok(disk_map)
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    runner.synthetic(generate);
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(disk_map: &DiskMap) -> Result<usize> {
+        let mut disk_map = disk_map.clone();
+        disk_map.defrag();
+        let answer = disk_map.checksum();
+        Ok(answer)
+    }
+
+    // TODO: Set the expected answer for the test input
+    assert_eq!(1928, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(disk_map: &DiskMap) -> Result<usize> {
+        let mut disk_map = disk_map.clone();
+        disk_map.defrag2();
+        let answer = disk_map.checksum();
+        Ok(answer)
+    }
+
+    assert_eq!(2858, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snapshot::assert_snapshot;
+
+    #[test]
+    fn test_unpack() {
+        let disk_map = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        assert_eq!(
+            disk_map.to_string(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        )
+    }
+
+    #[test]
+    fn test_defrag() {
+        let mut disk_map = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        disk_map.defrag();
+        assert_eq!(disk_map.to_string(), "0099811188827773336446555566")
+    }
+
+    #[test]
+    fn test_checksum() {
+        let mut disk_map = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        disk_map.defrag();
+        assert_eq!(disk_map.checksum(), 1928)
+    }
+
+    #[test]
+    fn test_layouts() {
+        // Ten files, so every id is one digit.
+        let input = generate(19);
+        let disk_map = parse(BufReader::new(input.as_bytes())).unwrap();
+        let (mut whole, mut blocks) = (disk_map.clone(), disk_map.clone());
+        blocks.defrag();
+        whole.defrag2();
+        let layouts = format!("{}{}\n{}\n{}\n", input, disk_map, blocks, whole);
+        assert_snapshot("2024-09/layouts", &layouts);
+    }
+
+    #[test]
+    fn test_defrag2() {
+        let mut disk_map = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        disk_map.defrag2();
+        assert_eq!(
+            disk_map.to_string(),
+            "00992111777.44.333....5555.6666.....8888.."
+        )
+    }
+}
@@ -1,15 +1,16 @@
-use adv_code_2024::*;
+//! Day 10: Hoof It
+//!
+//! Techniques: grid, dfs, path counting
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
 use util::grid::{Grid, Point};
 
+const YEAR: &str = "2024";
 const DAY: &str = "10";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 89010123
@@ -59,19 +60,8 @@ impl P10 for Grid<u8> {
 
     fn up_hill(&self, loc: &(usize, usize)) -> Vec<(usize, usize)> {
         let height = self.get(loc);
-        [(0, 1), (0, -1), (1, 0), (-1, 0)]
-            .iter()
-            .filter_map(|direction| {
-                if let (Some(y), Some(x)) = (
-                    loc.0.checked_add_signed(direction.0),
-                    loc.1.checked_add_signed(direction.1),
-                ) {
-                    if y < self.height && x < self.width && height + 1 == self.get(&(y, x)) {
-                        return Some((y, x));
-                    }
-                }
-                None
-            })
+        self.neighbors4(loc)
+            .filter(|next| height + 1 == self.get(next))
             .collect_vec()
     }
 
@@ -99,15 +89,17 @@ impl P10 for Grid<u8> {
     }
 }
 
+fn parse<R: BufRead>(reader: R) -> Result<Grid<u8>> {
+    Grid::<u8>::from_reader(reader)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::<u8>::from_reader(reader);
-
+    fn part1(grid: &Grid<u8>) -> Result<usize> {
         let answer = grid
             .get_trail_heads()
             .iter()
@@ -121,19 +113,13 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(36, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(36, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::<u8>::from_reader(reader);
-
+    fn part2(grid: &Grid<u8>) -> Result<usize> {
         let answer = grid
             .get_trail_heads()
             .iter()
@@ -142,14 +128,12 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(81, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(81, part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
 
 #[cfg(test)]
@@ -158,7 +142,7 @@ mod tests {
 
     #[test]
     fn test_get_trail_heads() {
-        let g = Grid::<u8>::from_reader(BufReader::new(TEST.as_bytes()));
+        let g = Grid::<u8>::from_reader(BufReader::new(TEST.as_bytes())).unwrap();
         assert_eq!(
             g.get_trail_heads(),
             vec![
@@ -177,7 +161,7 @@ mod tests {
 
     #[test]
     fn test_works() {
-        let g = Grid::<u8>::from_reader(BufReader::new(TEST.as_bytes()));
+        let g = Grid::<u8>::from_reader(BufReader::new(TEST.as_bytes())).unwrap();
         println!("{}", g);
         assert_eq!(
             g.search_trail((0, 2))
@@ -197,7 +181,7 @@ mod tests {
 
     #[test]
     fn test_count_distinct_paths() {
-        let g = Grid::<u8>::from_reader(BufReader::new(TEST.trim().as_bytes()));
+        let g = Grid::<u8>::from_reader(BufReader::new(TEST.trim().as_bytes())).unwrap();
         let tree = g.count_distinct_paths((0, 2));
 
         assert_eq!(tree, 20);
@@ -0,0 +1,126 @@
+//! Day 11: Plutonian Pebbles
+//!
+//! Techniques: memoization
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::{BufRead, BufReader};
+use tracing::debug;
+use util::memo::Memo;
+use util::num::{digit_count, split_digits};
+
+const YEAR: &str = "2024";
+const DAY: &str = "11";
+
+const TEST: &str = "\
+125 17
+";
+
+type Parsed = Vec<usize>;
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    let line = reader
+        .lines()
+        .next()
+        .ok_or_else(|| anyhow!("no stones"))??;
+    let stones = line.split(" ").map(|st| st.parse()).try_collect()?;
+    Ok(stones)
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(stones: &Parsed) -> Result<usize> {
+        let mut memo = Memo::new();
+        let answer = stones
+            .iter()
+            .map(|&stone| count_change(stone, 25, &mut memo))
+            .sum();
+        debug!(stats = %memo.stats(), "blinked");
+        Ok(answer)
+    }
+
+    assert_eq!(55312, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(stones: &Parsed) -> Result<usize> {
+        let mut memo = Memo::new();
+        let answer = stones
+            .iter()
+            .map(|&stone| count_change(stone, 75, &mut memo))
+            .sum();
+        debug!(stats = %memo.stats(), "blinked");
+        Ok(answer)
+    }
+
+    assert_eq!(65601038650482, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+/// How many stones `stone` turns into after `iteration` blinks.
+fn count_change(stone: usize, iteration: usize, memo: &mut Memo<(usize, usize), usize>) -> usize {
+    if iteration == 0 {
+        return 1;
+    }
+
+    memo.get_or_compute(&(stone, iteration), |memo| {
+        let digits = digit_count(stone);
+        if stone == 0 {
+            count_change(1, iteration - 1, memo)
+        } else if digits.is_multiple_of(2) {
+            let (high, low) = split_digits(stone, digits / 2).unwrap();
+            count_change(high, iteration - 1, memo) + count_change(low, iteration - 1, memo)
+        } else {
+            count_change(stone * 2024, iteration - 1, memo)
+        }
+    })
+}
+
+#[cfg(test)]
+fn blink(stones: &[usize]) -> Vec<usize> {
+    stones
+        .iter()
+        .flat_map(|num| -> Vec<usize> {
+            let snum = num.to_string();
+            if *num == 0 {
+                vec![1]
+            } else if snum.len().is_multiple_of(2) {
+                let t = snum.split_at(snum.len() / 2);
+                vec![t.0.parse().unwrap(), t.1.parse().unwrap()]
+            } else {
+                vec![num * 2024]
+            }
+        })
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(blink(&[125, 17]), vec![253000, 1, 7]);
+        assert_eq!(blink(&[253000, 1, 7]), vec![253, 0, 2024, 14168]);
+        assert_eq!(
+            blink(&[253, 0, 2024, 14168]),
+            vec![512072, 1, 20, 24, 28676032]
+        );
+        let mut state = vec![125, 17];
+        for _ in 0..25 {
+            state = blink(&state);
+        }
+        assert_eq!(state.len(), 55312);
+    }
+}
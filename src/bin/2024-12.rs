@@ -1,15 +1,17 @@
-use adv_code_2024::*;
+//! Day 12: Garden Groups
+//!
+//! Techniques: grid, flood fill
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
-use util::grid::Grid;
+use tracing::{debug, debug_span};
+use util::grid::{Direction, Grid};
 
+const YEAR: &str = "2024";
 const DAY: &str = "12";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 RRRRIICCFF
@@ -67,31 +69,23 @@ impl P12 for Grid<u8> {
     }
 
     fn get_neighbors(&self, start: usize) -> Vec<usize> {
-        [(0, 1), (1, 0), (0, -1), (-1, 0)]
-            .iter()
-            .filter_map(|direction| {
-                if let (Some(y), Some(x)) = (
-                    (start / self.height).checked_add_signed(direction.0),
-                    (start % self.height).checked_add_signed(direction.1),
-                ) {
-                    if y < self.height && x < self.width {
-                        return Some(y * self.height + x);
-                    }
-                }
-                None
-            })
+        self.neighbors4(&self.map_index(start))
+            .map(|(y, x)| y * self.width + x)
             .collect_vec()
     }
 }
 
+fn parse<R: BufRead>(reader: R) -> Result<Grid<u8>> {
+    Grid::<u8>::from_reader(reader)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::<u8>::from_reader(reader);
+    fn part1(grid: &Grid<u8>) -> Result<usize> {
         let region_map = grid.build_region_map();
         let region_area = region_map.iter().fold(HashMap::new(), |mut acc, entry| {
             let counter = acc.entry(*entry.1).or_insert(0);
@@ -116,19 +110,14 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(1930, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(1930, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::<u8>::from_reader(reader);
-        let region_map = grid.build_region_map();
+    fn part2(grid: &Grid<u8>) -> Result<usize> {
+        let region_map = debug_span!("regions").in_scope(|| grid.build_region_map());
         let region_area = (0..grid.array.len()).fold(HashMap::new(), |mut acc, index| {
             let region_id = region_map[&index];
             let counter = acc.entry(region_id).or_insert(0);
@@ -136,9 +125,9 @@ fn main() -> Result<()> {
             acc
         });
 
-        let region_sides = count_sides(grid, region_map);
-        for i in 0..11 {
-            println!("{:?} {}", region_area[&i], region_sides[&i]);
+        let region_sides = debug_span!("sides").in_scope(|| count_sides(grid, &region_map));
+        for (region, area) in region_area.iter().sorted() {
+            debug!(region, area, sides = region_sides[region]);
         }
         let answer = region_area
             .iter()
@@ -147,17 +136,15 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(1206, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(1206, part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
 
-fn count_sides(grid: Grid<u8>, region_map: HashMap<usize, i32>) -> HashMap<i32, usize> {
+fn count_sides(grid: &Grid<u8>, region_map: &HashMap<usize, i32>) -> HashMap<i32, usize> {
     (0..grid.array.len()).fold(HashMap::new(), |mut acc, index| {
         let region_id = region_map[&index];
 
@@ -172,68 +159,30 @@ fn count_sides(grid: Grid<u8>, region_map: HashMap<usize, i32>) -> HashMap<i32,
             _ => {
                 let mut s = 0;
                 if neighbors.len() == 2 {
-                    let n1 = (neighbors[0] / grid.height, neighbors[0] % grid.height);
-                    let n2 = (neighbors[1] / grid.height, neighbors[1] % grid.height);
+                    let n1 = grid.map_index(neighbors[0]);
+                    let n2 = grid.map_index(neighbors[1]);
                     if n1.0 != n2.0 && n1.1 != n2.1 {
                         s += 1;
                     }
                 };
 
+                // An inner corner where the region turns around a cell
+                // that is not part of it.
                 let val = grid.array[index];
-                if let (Some(y1), x1, y2, Some(x2)) = (
-                    (index / grid.height).checked_add_signed(-1),
-                    index % grid.height,
-                    index / grid.height,
-                    (index % grid.height).checked_add_signed(-1),
-                ) {
-                    if neighbors.contains(&(y1 * grid.height + x1))
-                        && neighbors.contains(&(y2 * grid.height + x2))
-                        && grid.get(&(y1, x2)) != val
-                    {
-                        s += 1
-                    }
-                }
-                if let (Some(y1), x1, y2, Some(x2)) = (
-                    (index / grid.height).checked_add_signed(-1),
-                    index % grid.height,
-                    index / grid.height,
-                    (index % grid.height).checked_add_signed(1),
-                ) {
-                    if x2 < grid.width
-                        && neighbors.contains(&(y1 * grid.height + x1))
-                        && neighbors.contains(&(y2 * grid.height + x2))
-                        && grid.get(&(y1, x2)) != val
-                    {
-                        s += 1
-                    }
-                }
-                if let (Some(y1), x1, y2, Some(x2)) = (
-                    (index / grid.height).checked_add_signed(1),
-                    index % grid.height,
-                    index / grid.height,
-                    (index % grid.height).checked_add_signed(-1),
-                ) {
-                    if y1 < grid.height
-                        && neighbors.contains(&(y1 * grid.height + x1))
-                        && neighbors.contains(&(y2 * grid.height + x2))
-                        && grid.get(&(y1, x2)) != val
-                    {
-                        s += 1
-                    }
-                }
-                if let (Some(y1), x1, y2, Some(x2)) = (
-                    (index / grid.height).checked_add_signed(1),
-                    index % grid.height,
-                    index / grid.height,
-                    (index % grid.height).checked_add_signed(1),
-                ) {
-                    if y1 < grid.height
-                        && x2 < grid.width
-                        && neighbors.contains(&(y1 * grid.height + x1))
-                        && neighbors.contains(&(y2 * grid.height + x2))
-                        && grid.get(&(y1, x2)) != val
-                    {
-                        s += 1
+                let pt = grid.map_index(index);
+                for vertical in [Direction::North, Direction::South] {
+                    for horizontal in [Direction::West, Direction::East] {
+                        let (Some(v), Some(h)) =
+                            (grid.step(&pt, vertical), grid.step(&pt, horizontal))
+                        else {
+                            continue;
+                        };
+                        if neighbors.contains(&(v.0 * grid.width + v.1))
+                            && neighbors.contains(&(h.0 * grid.width + h.1))
+                            && grid.get(&(v.0, h.1)) != val
+                        {
+                            s += 1
+                        }
                     }
                 }
                 s
@@ -253,7 +202,7 @@ mod tests {
 
     #[test]
     fn test_build_region_map() {
-        let grid = Grid::<u8>::from_reader(BufReader::new(TEST.as_bytes()));
+        let grid = Grid::<u8>::from_reader(BufReader::new(TEST.as_bytes())).unwrap();
         let region_map = grid.build_region_map();
         assert_eq!(region_map[&0], 0);
         assert_eq!(region_map[&1], 0);
@@ -285,10 +234,10 @@ BBCD
 BBCC
 EEEC
 ";
-        let grid = Grid::<u8>::from_reader(BufReader::new(test.as_bytes()));
+        let grid = Grid::<u8>::from_reader(BufReader::new(test.as_bytes())).unwrap();
         let region_map = grid.build_region_map();
         assert_eq!(
-            count_sides(grid, region_map),
+            count_sides(&grid, &region_map),
             HashMap::from([(0, 4), (1, 4), (2, 8), (3, 4), (4, 4)])
         )
     }
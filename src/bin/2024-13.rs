@@ -0,0 +1,181 @@
+//! Day 13: Claw Contraption
+//!
+//! Techniques: linear algebra, data parallelism
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::{BufRead, BufReader};
+use tracing::trace;
+use util::linalg::{solve_integer, LinalgError};
+use util::num::ext_gcd;
+use util::par;
+use util::parse::blocks;
+
+const YEAR: &str = "2024";
+const DAY: &str = "13";
+
+const TEST: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+/// How far buttons A and B move the claw, and where the prize is.
+#[derive(Debug, PartialEq)]
+struct Machine {
+    a: [i128; 2],
+    b: [i128; 2],
+    prize: [i128; 2],
+}
+
+impl Machine {
+    /// The tokens needed to win the prize moved by `offset`, if it can be
+    /// won at all.
+    fn cost(&self, offset: i128) -> Result<Option<i128>> {
+        let buttons = [vec![self.a[0], self.b[0]], vec![self.a[1], self.b[1]]];
+        let prize = self.prize.map(|p| p + offset);
+        Ok(match solve_integer(&buttons, &prize) {
+            Result::Ok(Some(presses)) => match presses[..] {
+                [a, b] if a >= 0 && b >= 0 => {
+                    trace!(a, b, "prize reachable");
+                    Some(3 * a + b)
+                }
+                _ => None,
+            },
+            Result::Ok(None) | Err(LinalgError::Inconsistent) => None,
+            Err(LinalgError::Underdetermined { .. }) => self.cheapest(prize),
+            Err(e) => bail!("cannot solve {:?}: {}", self, e),
+        })
+    }
+
+    /// The cheapest way to `prize` when the buttons move the claw along the
+    /// same line, so that many mixes of presses may reach it. Buttons that
+    /// do not both move it forwards on some axis never win.
+    fn cheapest(&self, prize: [i128; 2]) -> Option<i128> {
+        let axis = (0..2).find(|&i| self.a[i] > 0 && self.b[i] > 0)?;
+        let (a, b, prize) = (self.a[axis], self.b[axis], prize[axis]);
+        let (g, x, y) = ext_gcd(a, b);
+        if prize % g != 0 {
+            return None;
+        }
+        // Every solution is `(x + k * b / g, y - k * a / g)`, and the cost
+        // changes steadily with `k`, so the cheapest is at an end of the
+        // range where neither count is negative.
+        let (x, y) = (x * (prize / g), y * (prize / g));
+        let (da, db) = (b / g, a / g);
+        let (low, high) = (-x.div_euclid(da), y.div_euclid(db));
+        let cost = |k| 3 * (x + k * da) + y - k * db;
+        (low <= high).then(|| cost(low).min(cost(high)))
+    }
+}
+
+type Parsed = Vec<Machine>;
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    blocks(&input)
+        .iter()
+        .map(|block| {
+            let [a, b, prize] = block.lines().collect_vec()[..] else {
+                bail!(block.error("expected two buttons and a prize"));
+            };
+            Ok(Machine {
+                a: a.ints_n()?,
+                b: b.ints_n()?,
+                prize: prize.ints_n()?,
+            })
+        })
+        .collect()
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(machines: &Parsed) -> Result<i128> {
+        let mut answer = 0;
+        for cost in par::map(machines, |machine| machine.cost(0)) {
+            answer += cost?.unwrap_or_default();
+        }
+        Ok(answer)
+    }
+
+    // TODO: Set the expected answer for the test input
+    assert_eq!(480, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(machines: &Parsed) -> Result<i128> {
+        let mut answer = 0;
+        for cost in par::map(machines, |machine| machine.cost(10000000000000)) {
+            answer += cost?.unwrap_or_default();
+        }
+        Ok(answer)
+    }
+
+    assert_eq!(875318608908, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let machines = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        assert_eq!(
+            machines[0],
+            Machine {
+                a: [94, 34],
+                b: [22, 67],
+                prize: [8400, 5400]
+            }
+        );
+        assert_eq!(
+            machines[1],
+            Machine {
+                a: [26, 66],
+                b: [67, 21],
+                prize: [12748, 12176]
+            }
+        );
+    }
+
+    #[test]
+    fn test_cost() {
+        let machines = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        assert_eq!(machines[0].cost(0).unwrap(), Some(280));
+        assert_eq!(machines[1].cost(0).unwrap(), None);
+        assert_eq!(machines[0].cost(10000000000000).unwrap(), None);
+
+        let collinear = |a, b, prize| Machine { a, b, prize }.cost(0).unwrap();
+        assert_eq!(collinear([2, 4], [1, 2], [10, 20]), Some(10));
+        assert_eq!(collinear([6, 6], [1, 1], [12, 12]), Some(6));
+        assert_eq!(collinear([0, 6], [0, 1], [0, 13]), Some(7));
+        assert_eq!(collinear([4, 4], [6, 6], [5, 5]), None);
+        assert_eq!(collinear([1, 2], [2, 4], [3, 7]), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! Day 14: Restroom Redoubt
+//!
+//! Techniques: simulation, modular arithmetic
+
+use adv_code::util::grid::{Grid, Vec2};
+use adv_code::util::simulation::Simulation;
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use tracing::debug;
+use util::parse::lines;
+use viz::{Palette, Recorder, BLACK, GREEN};
+
+const YEAR: &str = "2024";
+const DAY: &str = "14";
+
+const TEST: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+/// The robots as (position, velocity), in a room of `height` by `width` tiles.
+#[derive(Clone)]
+struct Lobby {
+    height: isize,
+    width: isize,
+    guards: Vec<(Vec2, Vec2)>,
+}
+
+impl Lobby {
+    /// The room with a `@` on every robot.
+    fn picture(&self) -> Grid<&'static str> {
+        let (height, width) = (self.height as usize, self.width as usize);
+        let mut grid = Grid::new(vec!["."; height * width], height, width);
+        for (l, _) in &self.guards {
+            grid.set(l, "@");
+        }
+        grid
+    }
+}
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Lobby> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let (height, width) = if input.lines().count() > 20 {
+        (103, 101)
+    } else {
+        (7, 11)
+    };
+
+    let guards = lines(&input)
+        .map(|line| {
+            let [sx, sy, vx, vy] = line.ints_n()?;
+            ensure!(
+                (0..height).contains(&sy) && (0..width).contains(&sx),
+                line.error("robot outside the room")
+            );
+            Ok((Vec2::new(sy, sx), Vec2::new(vy, vx)))
+        })
+        .collect::<Result<_>>()?;
+    Ok(Lobby {
+        height,
+        width,
+        guards,
+    })
+}
+
+impl Simulation for Lobby {
+    type Key = Vec<Vec2>;
+
+    fn key(&self) -> Self::Key {
+        self.guards.iter().map(|(l, _)| *l).collect()
+    }
+
+    fn step(&mut self) -> bool {
+        let size = Vec2::new(self.height, self.width);
+        for (s, v) in self.guards.iter_mut() {
+            *s = (*s + *v).rem_euclid(size);
+        }
+        true
+    }
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(lobby: &Lobby) -> Result<usize> {
+        let (height, width) = (lobby.height, lobby.width);
+        let lobby = lobby.state_at(100).unwrap();
+
+        let sectors = lobby.guards.iter().fold(HashMap::new(), |mut acc, (l, _)| {
+            match l {
+                Vec2 { y, x } if *y < height / 2 && *x < width / 2 => {
+                    let entry = acc.entry(0).or_insert(0);
+                    *entry += 1;
+                }
+                Vec2 { y, x } if *y < height / 2 && *x > width / 2 => {
+                    let entry = acc.entry(1).or_insert(0);
+                    *entry += 1;
+                }
+                Vec2 { y, x } if *y > height / 2 && *x < width / 2 => {
+                    let entry = acc.entry(2).or_insert(0);
+                    *entry += 1;
+                }
+                Vec2 { y, x } if *y > height / 2 && *x > width / 2 => {
+                    let entry = acc.entry(3).or_insert(0);
+                    *entry += 1;
+                }
+                _ => (),
+            };
+
+            acc
+        });
+
+        let answer = sectors.iter().fold(1, |acc, entry| acc * entry.1);
+        Ok(answer)
+    }
+
+    assert_eq!(12, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(lobby: &Lobby) -> Result<usize> {
+        let (height, width) = (lobby.height, lobby.width);
+        let floor = Grid::new(
+            vec!["."; (height * width) as usize],
+            height as usize,
+            width as usize,
+        );
+        let palette = Palette::new(BLACK).with("@", GREEN);
+        let mut recorder = Recorder::new("robots", palette).every(50).scale(2);
+
+        let mut lobby = lobby.clone();
+        let steps = lobby
+            .run_until(|lobby| {
+                recorder.record_with(
+                    &floor,
+                    lobby
+                        .guards
+                        .iter()
+                        .map(|(l, _)| (l.to_point().unwrap(), GREEN)),
+                );
+                lobby.guards.iter().map(|(l, _)| l).all_unique()
+            })
+            .unwrap();
+
+        let grid = lobby.picture();
+        debug!(
+            steps,
+            "robots form the tree:\n{}",
+            grid.render(|s| s.chars().next().unwrap())
+        );
+        recorder.snapshot(&grid);
+        recorder.finish()?;
+        Ok(steps)
+    }
+
+    assert_eq!(1, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snapshot::assert_snapshot;
+
+    #[test]
+    fn test_picture() {
+        let mut lobby = parse(BufReader::new(TEST.as_bytes())).unwrap();
+        let steps = lobby.run_until(|lobby| lobby.guards.iter().map(|(l, _)| l).all_unique());
+        assert_eq!(steps, Some(1));
+        let picture = lobby.picture().render(|s| s.chars().next().unwrap());
+        assert_snapshot("2024-14/picture", &picture);
+    }
+}
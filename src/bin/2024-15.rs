@@ -1,16 +1,20 @@
-use adv_code_2024::util::grid::{Grid, Point};
-use adv_code_2024::*;
+//! Day 15: Warehouse Woes
+//!
+//! Techniques: grid, simulation
+
+use adv_code::util::grid::{Direction, Grid, Point};
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::fmt::Write;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
+use tracing::{debug, trace};
+use util::parse::blocks_n;
+use viz::{Palette, Recorder, BLACK, GREY, RED, YELLOW};
 
+const YEAR: &str = "2024";
 const DAY: &str = "15";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 ##########
@@ -43,11 +47,11 @@ const EMPTY: u8 = b'.';
 
 trait P15 {
     fn find_robot_position(&self) -> Option<Point>;
-    fn look_ahead_current_box(&self, cur: Point, direction: &char) -> Vec<Point>;
+    fn look_ahead_current_box(&self, cur: Point, direction: Direction) -> Vec<Point>;
     fn look_ahead_current_2w_box(
         &self,
         cur: Point,
-        direction: &char,
+        direction: Direction,
     ) -> Vec<HashSet<(Point, Point)>>;
 }
 
@@ -58,7 +62,7 @@ impl P15 for Grid<u8> {
             .find(|pt| self.get(pt) == ROBOT)
     }
 
-    fn look_ahead_current_box(&self, cur: Point, direction: &char) -> Vec<Point> {
+    fn look_ahead_current_box(&self, cur: Point, direction: Direction) -> Vec<Point> {
         let mut result = Vec::new();
         let mut m = cur;
         while {
@@ -72,12 +76,12 @@ impl P15 for Grid<u8> {
     fn look_ahead_current_2w_box(
         &self,
         cur: Point,
-        direction: &char,
+        direction: Direction,
     ) -> Vec<HashSet<(Point, Point)>> {
         fn next_move(
             grid: &Grid<u8>,
             cur: &HashSet<(Point, Point)>,
-            dir: &char,
+            dir: Direction,
         ) -> HashSet<(Point, Point)> {
             let mut result = HashSet::new();
             let mut move_closure = |next: Point, next_v: u8| {
@@ -118,7 +122,7 @@ impl P15 for Grid<u8> {
         let mut result = Vec::new();
         match self.get(&cur) {
             b'[' | b']' => {
-                if *direction == '<' || *direction == '>' {
+                if matches!(direction, Direction::West | Direction::East) {
                     let mut hres = HashSet::new();
                     let mut cur_box = complete_box(&cur, self.get(&cur));
                     while {
@@ -161,42 +165,70 @@ fn complete_box(half: &Point, val: u8) -> (Point, Point) {
     }
 }
 
+fn render(grid: &Grid<u8>) -> String {
+    (0..grid.height).fold(String::new(), |mut output, i| {
+        let _ = writeln!(
+            output,
+            "{}",
+            grid.array[grid.width * i..grid.width * (i + 1)]
+                .iter()
+                .map(|u| char::from(*u))
+                .collect::<String>()
+        );
+        output
+    })
+}
+
+fn palette() -> Palette<u8> {
+    Palette::new(BLACK)
+        .with(WALL, GREY)
+        .with(BOX, YELLOW)
+        .with(b'[', YELLOW)
+        .with(b']', YELLOW)
+        .with(ROBOT, RED)
+}
+
 trait Move {
-    fn next_move(&self, direction: &char) -> Point;
+    fn next_move(&self, direction: Direction) -> Point;
 }
 
 impl Move for Point {
-    fn next_move(&self, direction: &char) -> Point {
-        match direction {
-            '^' => (self.0.saturating_add_signed(-1), self.1),
-            'v' => (self.0 + 1, self.1),
-            '<' => (self.0, self.1.saturating_add_signed(-1)),
-            '>' => (self.0, self.1 + 1),
-            _ => unreachable!(),
-        }
+    /// The warehouse is walled in, so the robot and the boxes never step
+    /// off the grid.
+    fn next_move(&self, direction: Direction) -> Point {
+        direction.step(self).unwrap()
     }
 }
 
+/// The warehouse and the robot's moves.
+type Parsed = (Grid<u8>, Vec<Direction>);
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let [warehouse, moves] = blocks_n(&input)?;
+    let grid = Grid::<u8>::parse(warehouse)?;
+    let moves = moves
+        .lines()
+        .flat_map(|line| line.text.chars())
+        .map(Direction::try_from)
+        .collect::<Result<_>>()?;
+    Ok((grid, moves))
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader.read_to_string(&mut input)?;
-
-        let mut split = input.split("\n\n");
-        let grid = &mut Grid::<u8>::from_reader(BufReader::new(split.next().unwrap().as_bytes()));
-        let moves = split
-            .next()
-            .unwrap()
-            .lines()
-            .flat_map(|line| line.chars())
-            .collect_vec();
+    fn part1((grid, moves): &Parsed) -> Result<usize> {
+        let grid = &mut grid.clone();
         let mut robot = grid.find_robot_position().unwrap();
-        for m in &moves {
+        let mut recorder = Recorder::new("part1", palette()).every(20);
+        for &m in moves {
+            recorder.record(grid);
             let next_move = robot.next_move(m);
             match grid.get(&next_move) {
                 EMPTY => {
@@ -217,6 +249,8 @@ fn main() -> Result<()> {
                 _ => unreachable!("huh {}", char::from(grid.get(&next_move))),
             }
         }
+        recorder.snapshot(grid);
+        recorder.finish()?;
 
         let answer = grid
             .array
@@ -235,55 +269,28 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(10092, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(10092, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader.read_to_string(&mut input)?;
-
-        let mut split = input.split("\n\n");
-        let mut w = 0;
-        let mut h = 0;
+    fn part2((narrow, moves): &Parsed) -> Result<usize> {
         let mut v = Vec::new();
-        for line in split.next().unwrap().lines() {
-            if w == 0 {
-                w = line.len() * 2;
-            } else {
-                assert_eq!(w, line.len() * 2);
-            }
-            for c in line.chars() {
-                match c {
-                    'O' => v.extend([b'[', b']']),
-                    '@' => v.extend([b'@', b'.']),
-                    '#' | '.' => v.extend([c as u8].repeat(2)),
-                    _ => unreachable!(),
-                }
+        for &c in &narrow.array {
+            match c {
+                BOX => v.extend([b'[', b']']),
+                ROBOT => v.extend([ROBOT, EMPTY]),
+                WALL | EMPTY => v.extend([c].repeat(2)),
+                _ => unreachable!(),
             }
-            h += 1;
         }
-        let mut grid = Grid::<u8>::new(v, h, w);
-        let moves = split
-            .next()
-            .unwrap()
-            .lines()
-            .flat_map(|line| line.chars())
-            .collect_vec();
+        let mut grid = Grid::<u8>::new(v, narrow.height, narrow.width * 2);
         let mut robot = grid.find_robot_position().unwrap();
+        let mut recorder = Recorder::new("part2", palette()).every(20);
 
-        // let mut debug = false;
-        for m in &moves {
-            // if robot == (3,13) && *m == 'v' {
-            //     debug = true;
-            //     println!("HERE");
-            // }
+        for &m in moves {
+            recorder.record(&grid);
             let next_move = robot.next_move(m);
             match grid.get(&next_move) {
                 EMPTY => {
@@ -295,9 +302,9 @@ fn main() -> Result<()> {
                     let look_ahead = grid.look_ahead_current_2w_box(next_move, m);
                     // let (lhs, rhs) = look_ahead[look_ahead.len()-1];
                     match m {
-                        '<' | '>' => {
+                        Direction::West | Direction::East => {
                             let v = &look_ahead[0];
-                            let term = if *m == '<' {
+                            let term = if m == Direction::West {
                                 v.iter().fold((usize::MAX, usize::MAX), |acc, (l, _)| {
                                     if l.1 < acc.1 {
                                         *l
@@ -320,7 +327,7 @@ fn main() -> Result<()> {
                             if grid.get(&term) == EMPTY {
                                 grid.set(&robot, EMPTY);
                                 grid.set(&next_move, ROBOT);
-                                if *m == '<' {
+                                if m == Direction::West {
                                     for i in (term.1..next_move.1).step_by(2) {
                                         grid.set(&(term.0, i), b'[');
                                         grid.set(&(term.0, i + 1), b']');
@@ -334,9 +341,8 @@ fn main() -> Result<()> {
                                 robot = next_move;
                             }
                         }
-                        '^' | 'v' => {
+                        Direction::North | Direction::South => {
                             if !look_ahead.is_empty() {
-                                let offset = if *m == '^' { -1 } else { 1 };
                                 for v in look_ahead.iter().rev() {
                                     if v.iter()
                                         .all(|(l, r)| grid.get(l) == EMPTY && grid.get(r) == EMPTY)
@@ -344,22 +350,16 @@ fn main() -> Result<()> {
                                         continue;
                                     }
                                     for (l, r) in v {
-                                        grid.set(
-                                            &(l.0.saturating_add_signed(offset), l.1),
-                                            grid.get(l),
-                                        );
-                                        grid.set(
-                                            &(r.0.saturating_add_signed(offset), r.1),
-                                            grid.get(r),
-                                        );
+                                        grid.set(&l.next_move(m), grid.get(l));
+                                        grid.set(&r.next_move(m), grid.get(r));
                                         grid.set(l, EMPTY);
                                         grid.set(r, EMPTY);
                                     }
                                 }
                                 grid.set(&robot, EMPTY);
                                 let (l, r) = complete_box(&next_move, grid.get(&next_move));
-                                grid.set(&(l.0.saturating_add_signed(offset), l.1), grid.get(&l));
-                                grid.set(&(r.0.saturating_add_signed(offset), r.1), grid.get(&r));
+                                grid.set(&l.next_move(m), grid.get(&l));
+                                grid.set(&r.next_move(m), grid.get(&r));
                                 grid.set(&next_move, ROBOT);
                                 if l == next_move {
                                     grid.set(&r, EMPTY);
@@ -376,31 +376,11 @@ fn main() -> Result<()> {
                 WALL => (),
                 _ => unreachable!("huh {}", char::from(grid.get(&next_move))),
             }
-            // if grid.height > 20 {
-            //     println!("{}", m);
-            //     let s = (0..grid.height).fold(String::new(), |mut output, i| {
-            //         let _ = writeln!(
-            //             output,
-            //             "{:?}",
-            //             grid.array[grid.width * i..grid.width * (i + 1)].iter().map(|u| char::from(*u)).collect::<String>()
-            //         );
-            //         output
-            //     });
-            //     println!("{}", s);
-            // }
+            trace!(?m, "\n{}", render(&grid));
         }
-        let s = (0..grid.height).fold(String::new(), |mut output, i| {
-            let _ = writeln!(
-                output,
-                "{:?}",
-                grid.array[grid.width * i..grid.width * (i + 1)]
-                    .iter()
-                    .map(|u| char::from(*u))
-                    .collect::<String>()
-            );
-            output
-        });
-        println!("{}", s);
+        debug!("final warehouse:\n{}", render(&grid));
+        recorder.snapshot(&grid);
+        recorder.finish()?;
         let answer = grid
             .array
             .iter()
@@ -417,12 +397,10 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(9021, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(9021, part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
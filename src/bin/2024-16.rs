@@ -1,15 +1,19 @@
-use adv_code_2024::*;
+//! Day 16: Reindeer Maze
+//!
+//! Techniques: grid, dijkstra, backtracking
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
-use std::fs::File;
+use std::fmt::Write;
 use std::io::{BufRead, BufReader};
-use util::grid::{Grid, Point};
+use tracing::{debug, debug_span, trace};
+use util::grid::{Direction, Grid, Point};
+use viz::{Palette, Recorder, BLACK, BLUE, GREEN, GREY, RED};
 
+const YEAR: &str = "2024";
 const DAY: &str = "16";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 #################
@@ -35,61 +39,6 @@ const START: char = 'S';
 const END: char = 'E';
 const WALL: char = '#';
 
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-enum Direction {
-    East,
-    West,
-    North,
-    South,
-}
-
-impl Direction {
-    fn advance(&self, pt: &Point) -> Option<Point> {
-        use Direction::*;
-        if let (Some(y), Some(x)) = match self {
-            East => (Some(pt.0), pt.1.checked_add_signed(1)),
-            West => (Some(pt.0), pt.1.checked_add_signed(-1)),
-            North => (pt.0.checked_add_signed(-1), Some(pt.1)),
-            South => (pt.0.checked_add_signed(1), Some(pt.1)),
-        } {
-            return Some((y, x));
-        }
-        None
-    }
-
-    fn reverse(&self, pt: &Point) -> Option<Point> {
-        use Direction::*;
-        if let (Some(y), Some(x)) = match self {
-            East => (Some(pt.0), pt.1.checked_add_signed(-1)),
-            West => (Some(pt.0), pt.1.checked_add_signed(1)),
-            North => (pt.0.checked_add_signed(1), Some(pt.1)),
-            South => (pt.0.checked_add_signed(-1), Some(pt.1)),
-        } {
-            return Some((y, x));
-        }
-        None
-    }
-
-    fn turn_left(&self) -> Self {
-        use Direction::*;
-        match self {
-            East => North,
-            West => South,
-            North => West,
-            South => East,
-        }
-    }
-
-    fn turn_right(&self) -> Self {
-        use Direction::*;
-        match self {
-            East => South,
-            West => North,
-            North => East,
-            South => West,
-        }
-    }
-}
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 struct State {
     cost: usize,
@@ -116,8 +65,10 @@ fn dijkstra(
     grid: &Grid<char>,
     start: &State,
     dist: &mut HashMap<(Point, Direction), usize>,
+    recorder: &mut Recorder<char>,
 ) -> Option<usize> {
     // let mut dist = [usize::MAX].repeat(grid.array.len());
+    let _span = debug_span!("dijkstra").entered();
     let mut heap = BinaryHeap::from([*start]);
 
     // dist[start.position.0 * grid.width + start.position.1] = 0;
@@ -128,7 +79,14 @@ fn dijkstra(
         position,
     }) = heap.pop()
     {
+        recorder.record_with(
+            grid,
+            dist.keys()
+                .map(|(p, _)| (*p, BLUE))
+                .chain([(position, RED)]),
+        );
         if grid.get(&position) == END {
+            debug!(cost, states = dist.len(), "reached end");
             return Some(cost);
         }
         if cost > *dist.get(&(position, direction)).unwrap_or(&usize::MAX) {
@@ -144,7 +102,7 @@ fn dijkstra(
             {
                 // if next.cost < dist[next.position.0 * grid.width + next.position.1] {
                 heap.push(next);
-                // println!("{:?}", next);
+                trace!(?next, "queued");
                 // dist[next.position.0 * grid.width + next.position.1] = next.cost;
                 dist.insert((next.position, next.direction), next.cost);
             }
@@ -157,7 +115,7 @@ fn find_next_paths(grid: &Grid<char>, direction: &Direction, pos: &Point) -> Vec
     (0..3)
         .filter_map(|d| {
             if d == 0 {
-                if let Some(pt) = direction.advance(pos) {
+                if let Some(pt) = direction.step(pos) {
                     if grid.get(&pt) != WALL {
                         return Some(State {
                             cost: 1,
@@ -181,55 +139,80 @@ fn find_next_paths(grid: &Grid<char>, direction: &Direction, pos: &Point) -> Vec
         .collect_vec()
 }
 
+fn render(grid: &Grid<char>, path: &HashSet<Point>) -> String {
+    (0..grid.height).fold(String::new(), |mut output, y| {
+        for x in 0..grid.width {
+            let c = if path.contains(&(y, x)) {
+                'O'
+            } else {
+                grid.get(&(y, x))
+            };
+            let _ = write!(output, "{}", c);
+        }
+        let _ = writeln!(output);
+        output
+    })
+}
+
+fn palette() -> Palette<char> {
+    Palette::new(BLACK)
+        .with(WALL, GREY)
+        .with(START, GREEN)
+        .with(END, GREEN)
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    Grid::<char>::from_reader(reader)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::<char>::from_reader_char(reader);
+    fn part1(grid: &Grid<char>) -> Result<usize> {
         let start = grid.find(START).unwrap();
+        let mut recorder = Recorder::new("search", palette()).every(100);
         let answer = dijkstra(
-            &grid,
+            grid,
             &State {
                 cost: 0,
                 direction: Direction::East,
                 position: start,
             },
             &mut HashMap::new(),
+            &mut recorder,
         )
         .unwrap();
+        recorder.finish()?;
 
         Ok(answer)
     }
 
-    assert_eq!(11048, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(11048, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let grid = Grid::<char>::from_reader_char(reader);
+    fn part2(grid: &Grid<char>) -> Result<usize> {
         let start = grid.find(START).unwrap();
         let seen = &mut HashMap::new();
+        let mut recorder = Recorder::new("paths", palette()).every(100);
         let answer = dijkstra(
-            &grid,
+            grid,
             &State {
                 cost: 0,
                 direction: Direction::East,
                 position: start,
             },
             seen,
+            &mut recorder,
         )
         .unwrap();
 
-        // println!("{:?}", seen);
+        let _span = debug_span!("backtrack").entered();
         let mut queue = VecDeque::new();
         let end = grid.find(END).unwrap();
         for d in [
@@ -257,13 +240,14 @@ fn main() -> Result<()> {
         }) = queue.pop_front()
         {
             path.insert(position);
+            recorder.record_with(grid, path.iter().map(|p| (*p, GREEN)));
             if position == start {
                 break;
             }
 
             for node in (0..3).filter_map(|d| {
                 if d == 0 {
-                    if let Some(pt) = direction.reverse(&position) {
+                    if let Some(pt) = direction.opposite().step(&position) {
                         if grid.get(&pt) != WALL {
                             return Some(State {
                                 cost: cost - 1,
@@ -291,26 +275,16 @@ fn main() -> Result<()> {
                 }
             }
         }
-        // for node in &path {
-        //     grid.set(node, 'O');
-        // }
-        // let s = (0..grid.height).fold(String::new(), |mut output, i| {
-        //     for c in &grid.array[grid.width * i..grid.width * (i + 1)] {
-        //         let _ = write!(output, "{}", c);
-        //     }
-        //     let _ = writeln!(output);
-        //     output
-        // });
-        // println!("{}", s);
+        debug!("tiles on best paths:\n{}", render(grid, &path));
+        recorder.snapshot_with(grid, path.iter().map(|p| (*p, GREEN)));
+        recorder.finish()?;
         Ok(path.len())
     }
 
-    assert_eq!(64, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(64, part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
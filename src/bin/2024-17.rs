@@ -1,7 +1,9 @@
-use adv_code_2024::*;
+//! Day 17: Chronospatial Computer
+//!
+//! Techniques: vm, nom, reverse engineering
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use derive_more::TryFrom;
 use itertools::Itertools;
 use nom::bytes::complete::tag;
@@ -10,11 +12,11 @@ use nom::multi::separated_list1;
 use nom::sequence::tuple;
 use nom::IResult;
 use std::collections::HashSet;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
+use tracing::trace;
 
+const YEAR: &str = "2024";
 const DAY: &str = "17";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 Register A: 2024
@@ -53,7 +55,7 @@ enum Instruction {
     Cdv,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Registers {
     a: isize,
     b: isize,
@@ -180,7 +182,7 @@ impl Registers {
     }
 }
 
-fn parse(input: &str) -> IResult<&str, Registers> {
+fn registers(input: &str) -> IResult<&str, Registers> {
     use nom::character::complete::i32;
     let (rest, (_, a, _, _, b, _, _, c, _)) = tuple((
         tag("Register A: "),
@@ -215,54 +217,46 @@ fn program(input: &str) -> IResult<&str, Vec<u8>> {
     Result::Ok((rest, vec))
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+type Parsed = (Registers, Vec<u8>);
 
-    //region Part 1
-    println!("=== Part 1 ===");
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
 
-    fn part1<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader.read_to_string(&mut input)?;
+    let (rest, registers) = registers(&input).map_err(|e| anyhow!("bad registers: {}", e))?;
+    let (_, program) = program(rest).map_err(|e| anyhow!("bad program: {}", e))?;
+    Ok((registers, program))
+}
 
-        let (rest, mut registers) = parse(&input).expect("bad registers");
-        let (_, program) = program(rest).expect("bad program");
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
-        let result = registers.run(&program)?;
-        println!("result: {}", result);
-        Ok(0)
+    //region Part 1
+    fn part1((registers, program): &Parsed) -> Result<String> {
+        registers.clone().run(program)
     }
 
-    assert_eq!(0, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!("5,7,3,0", part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader.read_to_string(&mut input)?;
-
-        let (rest, _) = parse(&input).expect("bad registers");
-        let (_, program) = program(rest).expect("bad program");
+    fn part2((_, program): &Parsed) -> Result<usize> {
         let out = &mut Vec::new();
-        search_init_val(0, &program, &program, 1, out)?;
+        search_init_val(0, program, program, 1, out)?;
 
         Ok(*out.iter().min().unwrap() as usize)
     }
 
-    assert_eq!(117440, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(117440, part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
 
 fn search_init_val(
@@ -289,7 +283,7 @@ fn search_init_val(
                 candidates.insert(i);
                 if depth == program.len() {
                     out.push(some_a + i);
-                    // println!("{}", some_a + i)
+                    trace!(a = some_a + i, "program reproduces itself");
                 }
             }
         }
@@ -305,3 +299,33 @@ fn search_init_val(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snapshot::assert_snapshot;
+
+    #[test]
+    fn test_outputs() {
+        let runs = [
+            (729, "0,1,5,4,3,0"),
+            (10, "5,0,5,1,5,4"),
+            (2024, "0,1,5,4,3,0"),
+            (2024, "0,3,5,4,3,0"),
+            (117440, "0,3,5,4,3,0"),
+        ];
+        let outputs = runs
+            .iter()
+            .map(|&(a, program)| {
+                let input = format!(
+                    "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                    a, program
+                );
+                let (mut registers, program) = parse(BufReader::new(input.as_bytes())).unwrap();
+                let output = registers.run(&program).unwrap();
+                format!("A={:<7} {} -> {}\n", a, program.iter().join(","), output)
+            })
+            .collect::<String>();
+        assert_snapshot("2024-17/outputs", &outputs);
+    }
+}
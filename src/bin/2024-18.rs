@@ -1,15 +1,18 @@
-use adv_code_2024::*;
+//! Day 18: RAM Run
+//!
+//! Techniques: grid, dijkstra
+
+use adv_code::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::collections::BinaryHeap;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
+use tracing::{debug, trace};
 use util::grid::{Grid, Point};
+use viz::{Palette, Recorder, BLACK, GREY, RED};
 
+const YEAR: &str = "2024";
 const DAY: &str = "18";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 5,4
@@ -41,22 +44,29 @@ const TEST: &str = "\
 
 const WALL: char = '#';
 
+/// Where each byte falls, in order.
+type Parsed = Vec<Point>;
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    let incoming = reader
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| {
+            let mut parts = line.split(",").map(|part| part.parse::<usize>().unwrap());
+            let (x, y) = (parts.next().unwrap(), parts.next().unwrap());
+            (y, x)
+        })
+        .collect_vec();
+    Ok(incoming)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let incoming = &reader
-            .lines()
-            .map_while(Result::ok)
-            .map(|line| {
-                let mut parts = line.split(",").map(|part| part.parse::<usize>().unwrap());
-                let (x, y) = (parts.next().unwrap(), parts.next().unwrap());
-                (y, x)
-            })
-            .collect_vec();
+    fn part1(incoming: &Parsed) -> Result<usize> {
         let mut grid = if incoming.len() < 50 {
             Grid::new(['.'].repeat(7 * 7), 7, 7)
         } else {
@@ -69,33 +79,20 @@ fn main() -> Result<()> {
             }
             grid.set(b, '#');
         }
-        // println!("{}", grid);
+        trace!("fallen bytes:\n{}", grid);
         let answer = shortest_path(&grid, &(0, 0), &(grid.height - 1, grid.width - 1)).unwrap();
         // let answer = incoming.len();
         Ok(answer)
     }
 
     // TODO: Set the expected answer for the test input
-    assert_eq!(22, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(22, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let incoming = &reader
-            .lines()
-            .map_while(Result::ok)
-            .map(|line| {
-                let mut parts = line.split(",").map(|part| part.parse::<usize>().unwrap());
-                let (x, y) = (parts.next().unwrap(), parts.next().unwrap());
-                (y, x)
-            })
-            .collect_vec();
+    fn part2(incoming: &Parsed) -> Result<String> {
         let mut grid = if incoming.len() < 50 {
             Grid::new(['.'].repeat(7 * 7), 7, 7)
         } else {
@@ -110,42 +107,27 @@ fn main() -> Result<()> {
             grid.set(b, WALL);
         }
 
+        let palette = Palette::new(BLACK).with(WALL, GREY);
+        let mut recorder = Recorder::new("bytes", palette).every(10).scale(6);
         while shortest_path(&grid, &(0, 0), &(grid.height - 1, grid.width - 1)).is_some() {
             iteration += 1;
             let b = &incoming[iteration];
             grid.set(b, WALL);
+            recorder.record_with(&grid, [(*b, RED)]);
         }
-        println!("[Y,X]: {:?}", incoming[iteration]);
-        Ok(iteration)
+        debug!(iteration, "exit cut off");
+        recorder.snapshot_with(&grid, [(incoming[iteration], RED)]);
+        recorder.finish()?;
+        let (y, x) = incoming[iteration];
+        Ok(format!("{},{}", x, y))
     }
 
-    assert_eq!(20, part2(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!("6,1", part2(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
-}
-
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-enum Direction {
-    East,
-    West,
-    North,
-    South,
-}
-
-impl Direction {
-    fn offset(&self) -> (isize, isize) {
-        match self {
-            Direction::East => (0, 1),
-            Direction::West => (0, -1),
-            Direction::North => (-1, 0),
-            Direction::South => (1, 0),
-        }
-    }
+    runner.finish()
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -201,28 +183,8 @@ fn shortest_path(grid: &Grid<char>, start: &(usize, usize), end: &(usize, usize)
 }
 
 fn find_next_paths(grid: &Grid<char>, pos: &Point) -> Vec<State> {
-    // EAST, NORTH, SOUTH, WEST
-    [
-        Direction::North,
-        Direction::South,
-        Direction::West,
-        Direction::East,
-    ]
-    .iter()
-    .filter_map(|d| {
-        if let (Some(y), Some(x)) = (
-            pos.0.checked_add_signed(d.offset().0),
-            pos.1.checked_add_signed(d.offset().1),
-        ) {
-            if y >= grid.height || x >= grid.width || grid.get(&(y, x)) == WALL {
-                return None;
-            }
-            return Some(State {
-                cost: 1,
-                position: (y, x),
-            });
-        }
-        None
-    })
-    .collect_vec()
+    grid.neighbors4(pos)
+        .filter(|next| grid.get(next) != WALL)
+        .map(|position| State { cost: 1, position })
+        .collect_vec()
 }
@@ -0,0 +1,96 @@
+//! Day 19: Linen Layout
+//!
+//! Techniques: memoization, dynamic programming, data parallelism
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::{BufRead, BufReader};
+use tracing::debug;
+use util::memo::Memo;
+use util::par;
+use util::parse::blocks_n;
+
+const YEAR: &str = "2024";
+const DAY: &str = "19";
+
+const TEST: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+/// The available towel patterns and the designs to make from them.
+type Parsed = (Vec<String>, Vec<String>);
+
+fn parse<R: BufRead>(mut reader: R) -> Result<Parsed> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let [patterns, designs] = blocks_n(&input)?;
+    let patterns = patterns.comma_list()?;
+    let designs = designs
+        .lines()
+        .map(|line| line.text.to_owned())
+        .collect_vec();
+    Ok((patterns, designs))
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(parsed: &Parsed) -> Result<usize> {
+        let answer = arrangements(parsed)
+            .into_iter()
+            .filter(|&ways| ways > 0)
+            .count();
+        Ok(answer)
+    }
+
+    assert_eq!(6, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(parsed: &Parsed) -> Result<usize> {
+        let answer = arrangements(parsed).into_iter().sum();
+        Ok(answer)
+    }
+
+    assert_eq!(16, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+/// The number of ways to make each design. The designs a thread handles
+/// share one memo of suffixes.
+fn arrangements((patterns, designs): &Parsed) -> Vec<usize> {
+    let ways = |ways: &mut dyn FnMut(&str) -> usize, design: &str| {
+        if design.is_empty() {
+            return 1;
+        }
+        patterns
+            .iter()
+            .filter_map(|p| design.strip_prefix(p.as_str()))
+            .map(&mut *ways)
+            .sum()
+    };
+    par::map_with(designs, Memo::<String, usize>::new, |memo, design| {
+        let count = memo.recurse(design.as_str(), &ways);
+        debug!(design, count, stats = %memo.stats(), "arranged");
+        count
+    })
+}
@@ -0,0 +1,224 @@
+//! Day 20: Race Condition
+//!
+//! Techniques: grid, dijkstra, manhattan distance
+
+use adv_code::util::grid::{Direction, Grid, Point, Vec2};
+use adv_code::util::parse::Span;
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+
+const YEAR: &str = "2024";
+const DAY: &str = "20";
+
+const TEST: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+#[derive(Debug)]
+struct Map {
+    start: Point,
+    end: Point,
+    walls: Vec<Point>,
+    track: Vec<Point>,
+}
+
+impl Map {
+    fn from<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let (grid, [start, end]) =
+            Grid::parse_marked(Span::new(&input), ['S', 'E'], |c| match c {
+                '#' => Some(true),
+                '.' | 'S' | 'E' => Some(false),
+                _ => None,
+            })?;
+        let (walls, track) = (0..grid.height)
+            .cartesian_product(0..grid.width)
+            .partition(|pt| grid.get(pt));
+        Ok(Self {
+            start,
+            end,
+            walls,
+            track,
+        })
+    }
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Map> {
+    Map::from(reader)
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(map: &Map) -> Result<usize> {
+        let mut dist_map = HashMap::new();
+        let total = shortest_path(map, &mut dist_map).unwrap();
+
+        let answer = map
+            .track
+            .iter()
+            .flat_map(|pt| {
+                Direction::ORTHOGONAL
+                    .iter()
+                    .filter_map(|&d| {
+                        let start = Vec2::from(*pt);
+                        let p1 = (start + d.into()).to_point()?;
+                        let p2 = (start + Vec2::from(d) * 2).to_point()?;
+                        if map.walls.contains(&p1) && map.track.contains(&p2) {
+                            if let Some(saved) =
+                                (total - dist_map[pt]).checked_sub(total - dist_map[&p2] + 2)
+                            {
+                                if saved >= 100 {
+                                    return Some(saved);
+                                }
+                            }
+                        }
+                        None
+                    })
+                    .collect_vec()
+            })
+            .count();
+        Ok(answer)
+    }
+
+    assert_eq!(0, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(map: &Map) -> Result<usize> {
+        let mut dist_map = HashMap::new();
+        let total = shortest_path(map, &mut dist_map).unwrap();
+        dist_map = dist_map.iter().map(|(k, v)| (*k, total - v)).collect();
+        // let dist_cache = &mut HashMap::<(Point, Point), usize>::new();
+        let answer = map
+            .track
+            .iter()
+            .flat_map(|pt| {
+                let candidates = (-20..21)
+                    .rev()
+                    .flat_map(|i| {
+                        (-20..21)
+                            .flat_map(|j| {
+                                let offset = Vec2::new(i, j);
+                                if offset.manhattan() <= 20 && (i.abs() >= 2 || j.abs() >= 2) {
+                                    let p2 = (Vec2::from(*pt) + offset).to_point()?;
+                                    if map.track.contains(&p2) && dist_map[pt] > dist_map[&p2] {
+                                        return Some((p2, offset.manhattan()));
+                                    }
+                                }
+                                None
+                            })
+                            .collect::<HashSet<_>>()
+                    })
+                    .collect::<HashSet<_>>();
+
+                candidates
+                    .iter()
+                    .filter_map(|(p2, dist)| {
+                        if let Some(saved) = dist_map[pt].checked_sub(dist_map[p2] + dist) {
+                            if saved >= 100 {
+                                return Some(saved);
+                            }
+                        }
+                        None
+                    })
+                    .collect_vec()
+            })
+            .count();
+
+        Ok(answer)
+    }
+
+    assert_eq!(0, part2(&test)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct State {
+    cost: usize,
+    position: Point,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+fn shortest_path(map: &Map, dist: &mut HashMap<Point, usize>) -> Option<usize> {
+    let mut heap = BinaryHeap::from([State {
+        cost: 0,
+        position: map.start,
+    }]);
+
+    dist.insert(map.start, 0);
+    while let Some(State { cost, position }) = heap.pop() {
+        if position == map.end {
+            return Some(cost);
+        }
+        if cost > *dist.get(&position).unwrap_or(&usize::MAX) {
+            continue;
+        }
+        for next in find_next_paths(map, &position) {
+            let state = State {
+                cost: next.cost + cost,
+                position: next.position,
+            };
+
+            if state.cost < *dist.get(&state.position).unwrap_or(&usize::MAX) {
+                heap.push(state);
+                dist.insert(state.position, state.cost);
+            }
+        }
+    }
+    None
+}
+
+fn find_next_paths(map: &Map, location: &Point) -> Vec<State> {
+    Direction::ORTHOGONAL
+        .iter()
+        .filter_map(|&d| {
+            let position = d.step(location)?;
+            if map.track.contains(&position) {
+                Some(State { cost: 1, position })
+            } else {
+                None
+            }
+        })
+        .collect_vec()
+}
@@ -0,0 +1,119 @@
+//! Day 22: Monkey Market
+//!
+//! Techniques: prng, sliding window, data parallelism
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use util::par;
+
+const YEAR: &str = "2024";
+const DAY: &str = "22";
+
+const TEST: &str = "\
+1
+10
+100
+2024
+";
+
+const TEST2: &str = "\
+1
+2
+3
+2024
+";
+
+/// Each buyer's initial secret number.
+type Parsed = Vec<isize>;
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    reader.lines().map(|line| Ok(line?.parse()?)).collect()
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(secrets: &Parsed) -> Result<usize> {
+        let answer: isize = par::map(secrets, |&secret| {
+            let mut secret = secret;
+            (0..2000).for_each(|_| secret = evolve_next(secret));
+            secret
+        })
+        .into_iter()
+        .sum();
+        Ok(answer.try_into()?)
+    }
+
+    assert_eq!(37327623, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    fn part2(secrets: &Parsed) -> Result<usize> {
+        let price_bucket = par::map(secrets, |&secret| {
+            let mut secrets = vec![secret];
+            (0..2000).for_each(|_| secrets.push(evolve_next(secrets[secrets.len() - 1])));
+            secrets
+                .into_iter()
+                .rev()
+                .tuple_windows()
+                .map(|(a, b, c, d, e)| {
+                    (
+                        (
+                            (d % 10) - (e % 10),
+                            (c % 10) - (d % 10),
+                            (b % 10) - (c % 10),
+                            (a % 10) - (b % 10),
+                        ),
+                        a % 10,
+                    )
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, m| {
+            m.into_iter()
+                .for_each(|(k, v)| *acc.entry(k).or_default() += v);
+            acc
+        });
+
+        let answer: isize = *price_bucket.values().max().unwrap();
+        Ok(answer.try_into()?)
+    }
+
+    assert_eq!(23, part2(&parse(BufReader::new(TEST2.as_bytes()))?)?);
+
+    runner.solve_parsed(2, &input, part2);
+    //endregion
+
+    runner.finish()
+}
+
+const PRUNE_MAGIC: isize = 16777216;
+fn evolve_next(init: isize) -> isize {
+    let mut result = (init ^ (init * 64)) % PRUNE_MAGIC;
+    result = (result ^ (result / 32)) % PRUNE_MAGIC;
+    result = (result ^ (result * 2048)) % PRUNE_MAGIC;
+    result
+}
+
+#[test]
+fn test_next() {
+    assert_eq!(evolve_next(123), 15887950);
+    assert_eq!(evolve_next(15887950), 16495136);
+    assert_eq!(evolve_next(16495136), 527345);
+    assert_eq!(evolve_next(527345), 704524);
+    assert_eq!(evolve_next(704524), 1553684);
+    assert_eq!(evolve_next(1553684), 12683156);
+    assert_eq!(evolve_next(12683156), 11100544);
+    assert_eq!(evolve_next(11100544), 12249484);
+    assert_eq!(evolve_next(12249484), 7753432);
+    assert_eq!(evolve_next(7753432), 5908254);
+}
@@ -0,0 +1,199 @@
+//! Day 23: LAN Party
+//!
+//! Techniques: graph, cliques, bron-kerbosch, arena tree
+
+use adv_code::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use util::arena_tree::ArenaTree;
+
+const YEAR: &str = "2024";
+const DAY: &str = "23";
+
+const TEST: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+/// The network connections, one pair of computers per link.
+type Parsed = Vec<(String, String)>;
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    reader
+        .lines()
+        .map(|line| {
+            let line = line?;
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| anyhow!("bad connection: {}", line))?;
+            Ok((a.to_owned(), b.to_owned()))
+        })
+        .collect()
+}
+
+fn main() -> Result<()> {
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
+
+    //region Part 1
+    fn part1(links: &Parsed) -> Result<usize> {
+        let mut arena_graph = ArenaTree::<String>::new();
+        for (a, b) in links {
+            let c1 = arena_graph.node(a.clone());
+            let c2 = arena_graph.node(b.clone());
+            arena_graph.arena[c1].children.insert(c2);
+            arena_graph.arena[c2].children.insert(c1);
+        }
+
+        let mut v = HashSet::new();
+        for node in &arena_graph.arena {
+            if node.val.starts_with("t") {
+                for c in &node.children {
+                    for c1 in node.children.intersection(&arena_graph.arena[*c].children) {
+                        let mut triangle = [node.idx, *c, *c1];
+                        triangle.sort();
+                        v.insert(triangle);
+                    }
+                }
+            }
+        }
+
+        let answer = v.len();
+        Ok(answer)
+    }
+
+    assert_eq!(7, part1(&test)?);
+
+    runner.solve_parsed(1, &input, part1);
+    //endregion
+
+    //region Part 2
+    /// Grows a network greedily from every computer and keeps the largest.
+    fn part2(links: &Parsed) -> Result<String> {
+        // let mut arena_graph = ArenaTree::<String>::new();
+        let mut computers = HashSet::new();
+        let mut connections = HashSet::new();
+        for (a, b) in links {
+            computers.extend([a.clone(), b.clone()]);
+            connections.extend([(a.clone(), b.clone()), (b.clone(), a.clone())]);
+        }
+
+        let mut networks = computers.iter().map(|c| HashSet::from([c])).collect_vec();
+
+        for network in &mut networks {
+            for c1 in computers.iter() {
+                if network
+                    .iter()
+                    .all(|&c0| connections.contains(&(c0.clone(), c1.clone())))
+                {
+                    network.insert(c1);
+                }
+            }
+        }
+
+        let n = networks.iter().max_by_key(|s| s.len()).unwrap();
+        Ok(n.iter().sorted().join(","))
+    }
+
+    /// Lists the maximal cliques with Bron–Kerbosch and keeps the largest.
+    fn part2_bron_kerbosch(links: &Parsed) -> Result<String> {
+        let mut graph = HashMap::<&str, HashSet<&str>>::new();
+        for (a, b) in links {
+            graph.entry(a).or_default().insert(b);
+            graph.entry(b).or_default().insert(a);
+        }
+        let mut largest = Vec::new();
+        let candidates = graph.keys().copied().collect();
+        bron_kerbosch(
+            &graph,
+            &mut Vec::new(),
+            candidates,
+            HashSet::new(),
+            &mut largest,
+        );
+        Ok(largest.iter().sorted().join(","))
+    }
+
+    const PART2: &Variants<Parsed, String> =
+        &[("greedy", part2), ("bron-kerbosch", part2_bron_kerbosch)];
+
+    for (_, part2) in PART2 {
+        assert_eq!("co,de,ka,ta", part2(&test)?);
+    }
+
+    runner.solve_variants(2, &input, PART2);
+    //endregion
+
+    runner.finish()
+}
+
+/// Extends `clique` with the `candidates`, none of the `excluded` ones, into
+/// every maximal clique, keeping the largest in `largest`. Pivoting on the
+/// vertex with the most neighbors skips the candidates it would cover.
+fn bron_kerbosch<'a>(
+    graph: &HashMap<&'a str, HashSet<&'a str>>,
+    clique: &mut Vec<&'a str>,
+    mut candidates: HashSet<&'a str>,
+    mut excluded: HashSet<&'a str>,
+    largest: &mut Vec<&'a str>,
+) {
+    let Some(&pivot) = candidates.union(&excluded).max_by_key(|&&v| graph[v].len()) else {
+        if clique.len() > largest.len() {
+            largest.clone_from(clique);
+        }
+        return;
+    };
+    let skipped = &graph[pivot];
+    let vertices = candidates
+        .iter()
+        .copied()
+        .filter(|v| !skipped.contains(v))
+        .collect_vec();
+    for v in vertices {
+        let neighbors = &graph[v];
+        clique.push(v);
+        bron_kerbosch(
+            graph,
+            clique,
+            candidates.intersection(neighbors).copied().collect(),
+            excluded.intersection(neighbors).copied().collect(),
+            largest,
+        );
+        clique.pop();
+        candidates.remove(v);
+        excluded.insert(v);
+    }
+}
@@ -1,50 +1,54 @@
+//! Day NN: <TITLE>
+//!
+//! Techniques: <TECHNIQUES>
+//! Complexity: <PART 1>, <PART 2>
+
 use anyhow::*;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use adv_code_2024::*;
+use adv_code::*;
 
+const YEAR: &str = "YYYY"; // TODO: Fill the year
 const DAY: &str = "NN"; // TODO: Fill the day
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 <TEST-INPUT>
 "; // TODO: Add the test input
 
+type Parsed = Vec<String>; // TODO: Pick what both parts work from
+
+fn parse<R: BufRead>(reader: R) -> Result<Parsed> {
+    // TODO: Parse the input once for both parts
+    let lines = reader.lines().map_while(Result::ok).collect();
+    Ok(lines)
+}
+
 fn main() -> Result<()> {
-    start_day(DAY);
+    let mut runner = Runner::new(YEAR, DAY)?;
+    let test = parse(BufReader::new(TEST.as_bytes()))?;
+    let input = runner.parse(parse);
 
     //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    fn part1(input: &Parsed) -> Result<usize> {
         // TODO: Solve Part 1 of the puzzle
-        let answer = reader.lines().flatten().count();
+        let answer = input.len();
         Ok(answer)
     }
 
     // TODO: Set the expected answer for the test input
-    assert_eq!(0, part1(BufReader::new(TEST.as_bytes()))?);
+    assert_eq!(0, part1(&test)?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
+    runner.solve_parsed(1, &input, part1);
     //endregion
 
     //region Part 2
-    // println!("\n=== Part 2 ===");
-    //
-    // fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    // fn part2(input: &Parsed) -> Result<usize> {
     //     Ok(0)
     // }
     //
-    // assert_eq!(0, part2(BufReader::new(TEST.as_bytes()))?);
+    // assert_eq!(0, part2(&test)?);
     //
-    // let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    // let result = time_snippet!(part2(input_file)?);
-    // println!("Result = {}", result);
+    // runner.solve_parsed(2, &input, part2);
     //endregion
 
-    Ok(())
+    runner.finish()
 }
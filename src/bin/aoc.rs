@@ -0,0 +1,397 @@
+use adv_code::answers::Answers;
+use adv_code::registry::{self, DayId};
+use adv_code::runner::{Format, Record, Status, CSV_HEADER};
+use adv_code::*;
+use anyhow::*;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const USAGE: &str = "\
+usage: aoc <command> [args]
+
+commands:
+    run [<year>[/<day>]] [--release] [--format text|json|csv] [--accept]
+        [--speedup] [--fresh]      solve the selected days (all by default);
+                                   --accept stores their answers as correct,
+                                   --speedup times each part serially as well;
+                                   answers come from .aoc-cache unless the day's
+                                   binary or input changed, or --fresh is given
+    watch <year>/<day> [--release] re-run a day whenever its sources or inputs change
+    report [<year>[/<day>]] [--release] [--out <dir>]
+                                   solve the selected days and write index.html and
+                                   README.md with their answers, timings, memory and
+                                   final grids to <dir> (report/ by default)
+    profile [<year>[/<day>]] [--release]
+                                   time every part on ever larger inputs, truncated or
+                                   synthetic, and warn when one grows faster than the
+                                   Complexity its day declares
+    compare [<year>[/<day>]] [--release]
+                                   run every implementation of the parts that have
+                                   several, check they agree and time them
+    find [--tag <tag>]... [--uses <library>]... [<text>]
+                                   list the days with all of these techniques, using
+                                   all of these libraries (such as nom or grid) and
+                                   with <text> in their title, and where they are
+    encrypt                        store an encrypted copy of every input and answers
+                                   file next to it, to be committed
+    rekey                          re-encrypt every encrypted file with the passphrase
+                                   in $AOC_NEW_PASSPHRASE
+
+run, watch, report, profile and compare pass -v, -vv and -vvv on to the days to show their logs.
+Built with --features parallel, aoc builds the days with it too.
+
+The passphrase comes from $AOC_PASSPHRASE or the first line of .aoc-passphrase.
+";
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("watch") => {
+            let mut spec = None;
+            let mut release = false;
+            let mut extra = Vec::new();
+            for arg in &args[1..] {
+                match arg.as_str() {
+                    "--release" => release = true,
+                    _ if is_verbosity(arg) => extra.push(arg.as_str()),
+                    _ if spec.is_none() => spec = Some(arg.as_str()),
+                    _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+                }
+            }
+            let spec = spec.ok_or_else(|| anyhow!("missing day\n\n{}", USAGE))?;
+            let id = single_day(spec)?;
+            watch::watch(id, release, &extra, Duration::from_millis(500))
+        }
+        Some("report") => report(&args[1..]),
+        Some("profile") => profile(&args[1..]),
+        Some("compare") => compare(&args[1..]),
+        Some("find") => find(&args[1..]),
+        Some("encrypt") => {
+            ensure!(args.len() == 1, "encrypt takes no arguments\n\n{}", USAGE);
+            let sealed = vault::encrypt_all()?;
+            for path in &sealed {
+                println!("{}", path.display());
+            }
+            println!("encrypted {} new or changed file(s)", sealed.len());
+            Ok(())
+        }
+        Some("rekey") => {
+            ensure!(args.len() == 1, "rekey takes no arguments\n\n{}", USAGE);
+            rekey()
+        }
+        _ => bail!("{}", USAGE),
+    }
+}
+
+fn rekey() -> Result<()> {
+    let old = vault::passphrase()?.ok_or_else(|| {
+        anyhow!(
+            "no current passphrase: set {} or write it to {}",
+            vault::PASSPHRASE_ENV,
+            vault::PASSPHRASE_FILE
+        )
+    })?;
+    let new = std::env::var("AOC_NEW_PASSPHRASE")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| anyhow!("set AOC_NEW_PASSPHRASE to the new passphrase"))?;
+    let rekeyed = vault::rekey(&old, &new)?;
+    println!("re-encrypted {} file(s)", rekeyed.len());
+    if std::env::var_os(vault::PASSPHRASE_ENV).is_some() {
+        println!("update {} to the new passphrase", vault::PASSPHRASE_ENV);
+    } else {
+        std::fs::write(vault::PASSPHRASE_FILE, format!("{}\n", new))?;
+        println!("updated {}", vault::PASSPHRASE_FILE);
+    }
+    Ok(())
+}
+
+fn single_day(spec: &str) -> Result<DayId> {
+    match registry::select(&registry::days(), Some(spec))?[..] {
+        [id] => Ok(id),
+        _ => bail!("`{}` selects more than one day", spec),
+    }
+}
+
+fn is_verbosity(arg: &str) -> bool {
+    matches!(arg, "-v" | "-vv" | "-vvv" | "--verbose")
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let mut spec = None;
+    let mut release = false;
+    let mut extra = Vec::new();
+    let mut accept = false;
+    let mut fresh = false;
+    let mut format = Format::Text;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--release" => release = true,
+            "--accept" => accept = true,
+            "--fresh" => fresh = true,
+            "--speedup" => extra.push(arg.as_str()),
+            _ if is_verbosity(arg) => extra.push(arg.as_str()),
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--format needs a value"))?
+                    .parse()?
+            }
+            _ if spec.is_none() => spec = Some(arg.as_str()),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+
+    if format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+    let mut failed = 0;
+    let mut hits = 0;
+    let days = registry::select(&registry::days(), spec)?;
+    for &id in &days {
+        // Logs and serial timings only come from an actual run.
+        let fresh = fresh || !extra.is_empty();
+        let (records, cached) = match cache::run(id, release, &extra, fresh) {
+            Result::Ok(run) => run,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                failed += 1;
+                continue;
+            }
+        };
+        if accept {
+            let mut answers = Answers::load(id)?;
+            for record in &records {
+                if let Some(answer) = &record.answer {
+                    answers.set(record.part, answer);
+                }
+            }
+            answers.save()?;
+        }
+        for record in &records {
+            if record.status != Status::Ok && !(accept && record.answer.is_some()) {
+                failed += 1;
+            }
+            print(record, format);
+        }
+        if cached {
+            hits += 1;
+        }
+    }
+    if hits > 0 {
+        eprintln!(
+            "{} of {} day(s) from the cache; --fresh solves them again",
+            hits,
+            days.len()
+        );
+    }
+    ensure!(failed == 0, "{} part(s) failed", failed);
+    Ok(())
+}
+
+fn report(args: &[String]) -> Result<()> {
+    let mut spec = None;
+    let mut release = false;
+    let mut out = PathBuf::from("report");
+    let mut extra = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--release" => release = true,
+            "--out" => {
+                out = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--out needs a value"))?
+                    .into()
+            }
+            _ if is_verbosity(arg) => extra.push(arg.as_str()),
+            _ if spec.is_none() => spec = Some(arg.as_str()),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+
+    let mut entries = Vec::new();
+    for id in registry::select(&registry::days(), spec)? {
+        eprintln!("{}...", id);
+        for stale in report::images(id) {
+            std::fs::remove_file(stale)?;
+        }
+        let (records, error) = match id.run(release, &extra) {
+            Result::Ok(records) => (records, None),
+            Err(e) => (Vec::new(), Some(format!("{:#}", e))),
+        };
+        let meta = id.meta()?;
+        // The final states come from a second run, so that drawing them
+        // does not count towards the timings of the first.
+        let solved = records.iter().any(|r| r.answer.is_some());
+        let images = if solved && meta.find_use("viz").is_some() {
+            let record = [&["--record", "last"], &extra[..]].concat();
+            match id.run(release, &record) {
+                Result::Ok(_) => report::images(id),
+                Err(e) => {
+                    eprintln!("{}: no final states: {:#}", id, e);
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        entries.push(report::Entry {
+            id,
+            meta,
+            records,
+            error,
+            images,
+        });
+    }
+    for path in report::write(&out, &entries)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn profile(args: &[String]) -> Result<()> {
+    let mut spec = None;
+    let mut release = false;
+    let mut extra = vec!["--profile"];
+    for arg in args {
+        match arg.as_str() {
+            "--release" => release = true,
+            _ if is_verbosity(arg) => extra.push(arg.as_str()),
+            _ if spec.is_none() => spec = Some(arg.as_str()),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+
+    let mut failed = 0;
+    for id in registry::select(&registry::days(), spec)? {
+        if let Err(e) = id.run_attached(release, &extra) {
+            eprintln!("{:#}", e);
+            failed += 1;
+        }
+    }
+    ensure!(failed == 0, "{} day(s) failed", failed);
+    Ok(())
+}
+
+fn compare(args: &[String]) -> Result<()> {
+    let mut spec = None;
+    let mut release = false;
+    let mut extra = vec!["--compare"];
+    for arg in args {
+        match arg.as_str() {
+            "--release" => release = true,
+            _ if is_verbosity(arg) => extra.push(arg.as_str()),
+            _ if spec.is_none() => spec = Some(arg.as_str()),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+
+    let mut days = Vec::new();
+    for id in registry::select(&registry::days(), spec)? {
+        if id.meta()?.variants {
+            days.push(id);
+        }
+    }
+    ensure!(
+        !days.is_empty(),
+        "no selected day has several implementations"
+    );
+    let mut failed = 0;
+    for id in days {
+        if let Err(e) = id.run_attached(release, &extra) {
+            eprintln!("{:#}", e);
+            failed += 1;
+        }
+    }
+    ensure!(failed == 0, "{} day(s) failed", failed);
+    Ok(())
+}
+
+fn find(args: &[String]) -> Result<()> {
+    let mut tags = Vec::new();
+    let mut uses = Vec::new();
+    let mut text = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .map(String::as_str)
+                .ok_or_else(|| anyhow!("{} needs a value", flag))
+        };
+        match arg.as_str() {
+            "--tag" => tags.push(value("--tag")?),
+            "--uses" => uses.push(value("--uses")?),
+            _ if text.is_none() && !arg.starts_with('-') => text = Some(arg.to_lowercase()),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+
+    let mut found = 0;
+    for id in registry::days() {
+        let meta = id.meta()?;
+        let title = meta.title.as_deref().unwrap_or_default();
+        let Some(found_uses) = uses
+            .iter()
+            .map(|name| meta.find_use(name))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        if !tags.iter().all(|tag| meta.has_tag(tag))
+            || text
+                .as_ref()
+                .is_some_and(|t| !title.to_lowercase().contains(t))
+        {
+            continue;
+        }
+        let mut location = id.source_path().display().to_string();
+        if let Some(first) = found_uses.first() {
+            location = format!("{}:{}", location, first.line);
+        }
+        println!(
+            "{}  {:<28} {:<24} {}",
+            id,
+            title,
+            location,
+            meta.techniques.join(", ")
+        );
+        found += 1;
+    }
+    ensure!(found > 0, "no day matches");
+    Ok(())
+}
+
+fn print(record: &Record, format: Format) {
+    match format {
+        Format::Text => {
+            let outcome = match (&record.answer, record.status) {
+                (Some(answer), Status::Wrong) => format!(
+                    "{} (wrong, expected {})",
+                    answer,
+                    record.expected.as_deref().unwrap_or_default()
+                ),
+                (Some(answer), _) => answer.clone(),
+                (None, _) => format!("error: {}", record.error.as_deref().unwrap_or_default()),
+            };
+            let speedup = record
+                .serial_ms
+                .map(|serial_ms| format!(" {:>6.2}x", serial_ms / record.elapsed_ms))
+                .unwrap_or_default();
+            println!(
+                "{}/{:02} part {}: {:<20} {:>10.3}ms{}{}",
+                record.year,
+                record.day,
+                record.part,
+                outcome,
+                record.elapsed_ms,
+                speedup,
+                if record.cached { " (cached)" } else { "" }
+            );
+        }
+        Format::Json => println!("{}", serde_json::to_string(record).unwrap()),
+        Format::Csv => println!("{}", record.to_csv()),
+    }
+}
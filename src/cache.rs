@@ -0,0 +1,176 @@
+//! Answers of earlier runs, kept in `.aoc-cache/<year>/<day>.json` so `aoc
+//! run` can skip days whose binary and input are both unchanged.
+//!
+//! The binary stands in for everything that decides an answer: the day's
+//! source, the library, dependencies, features, profile and compiler all
+//! end up in its bytes, so a hash of them changes with any of these. Only a
+//! run that went to the end without failing is stored, and the answers are
+//! checked again against the accepted ones every time they are reused.
+
+use crate::answers::Answers;
+use crate::registry::DayId;
+use crate::runner::{hash_input, Record, Status};
+use crate::vault;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const CACHE_DIR: &str = ".aoc-cache";
+
+/// What the answers depend on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Key {
+    /// Hash of the day's binary.
+    pub build: String,
+    /// Hash of the plain input, as in [`Record::input_hash`].
+    pub input_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    key: Key,
+    records: Vec<Record>,
+}
+
+fn path(id: DayId) -> PathBuf {
+    PathBuf::from(format!("{}/{}/{:02}.json", CACHE_DIR, id.year, id.day))
+}
+
+/// The key of `id` as it is now, building it if needed. `None` if it has no
+/// input to hash.
+pub fn key(id: DayId, release: bool) -> Result<Option<Key>> {
+    let Some(input) = vault::read(&id.input_path())? else {
+        return Ok(None);
+    };
+    let binary = id.build(release)?;
+    let bytes =
+        std::fs::read(&binary).with_context(|| format!("cannot read {}", binary.display()))?;
+    Ok(Some(Key {
+        build: hash_input(&bytes),
+        input_hash: hash_input(&input),
+    }))
+}
+
+/// The records stored for `key`, checked against the accepted answers. A
+/// missing or unreadable cache file is a miss.
+pub fn load(id: DayId, key: &Key) -> Result<Option<Vec<Record>>> {
+    let Result::Ok(text) = std::fs::read_to_string(path(id)) else {
+        return Ok(None);
+    };
+    let Result::Ok(entry) = serde_json::from_str::<Entry>(&text) else {
+        return Ok(None);
+    };
+    if entry.key != *key {
+        return Ok(None);
+    }
+    let answers = Answers::load(id)?;
+    Ok(Some(verify(entry.records, |part| answers.get(part))))
+}
+
+/// Stores the records of a run that went to the end, if they all have an
+/// answer for the input of `key`.
+pub fn store(id: DayId, key: &Key, records: &[Record]) -> Result<()> {
+    let complete = !records.is_empty()
+        && records.iter().all(|r| {
+            r.answer.is_some() && r.input_hash.as_deref() == Some(key.input_hash.as_str())
+        });
+    if !complete {
+        return Ok(());
+    }
+    let path = path(id);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let entry = Entry {
+        key: key.clone(),
+        records: records.to_vec(),
+    };
+    std::fs::write(&path, serde_json::to_string_pretty(&entry)?)
+        .with_context(|| format!("cannot write {}", path.display()))
+}
+
+/// The stored records marked as cached, with their expected answers and
+/// status as of now, the accepted answers having maybe changed since they
+/// were stored.
+fn verify<'a>(records: Vec<Record>, expected: impl Fn(u8) -> Option<&'a str>) -> Vec<Record> {
+    records
+        .into_iter()
+        .map(|mut record| {
+            record.cached = true;
+            record.expected = expected(record.part).map(String::from);
+            record.status = match (&record.answer, &record.expected) {
+                (None, _) => Status::Error,
+                (Some(answer), Some(expected)) if answer != expected => Status::Wrong,
+                _ => Status::Ok,
+            };
+            record
+        })
+        .collect()
+}
+
+/// Runs `id` as [`DayId::run`] does unless the cache has its answers
+/// already, telling which it was. `fresh` always runs it.
+pub fn run(
+    id: DayId,
+    release: bool,
+    extra_args: &[&str],
+    fresh: bool,
+) -> Result<(Vec<Record>, bool)> {
+    let key = key(id, release)?;
+    if let (Some(key), false) = (&key, fresh) {
+        if let Some(records) = load(id, key)? {
+            return Ok((records, true));
+        }
+    }
+    let (records, success) = id.run_to_end(release, extra_args)?;
+    if let (Some(key), true) = (&key, success) {
+        store(id, key, &records)?;
+    }
+    Ok((records, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u8, answer: Option<&str>) -> Record {
+        Record {
+            year: 2024,
+            day: 5,
+            part,
+            answer: answer.map(String::from),
+            expected: None,
+            status: Status::Ok,
+            input_hash: Some("ab".into()),
+            parse_ms: None,
+            elapsed_ms: 1.,
+            serial_ms: None,
+            peak_kib: None,
+            error: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut stored = record(2, Some("123"));
+        stored.status = Status::Wrong;
+        let records = verify(
+            vec![record(1, Some("143")), stored, record(3, None)],
+            |part| {
+                [None, Some("143"), Some("124")]
+                    .get(usize::from(part))
+                    .copied()
+                    .flatten()
+            },
+        );
+        assert_eq!(records[0].status, Status::Ok);
+        assert_eq!(records[1].status, Status::Wrong);
+        assert_eq!(records[1].expected.as_deref(), Some("124"));
+        assert_eq!(records[2].status, Status::Error);
+        assert!(records.iter().all(|r| r.cached));
+
+        let records = verify(records, |_| None);
+        assert!(records[..2].iter().all(|r| r.status == Status::Ok));
+    }
+}
@@ -1,11 +1,33 @@
-pub fn start_day(day: &str) {
-    println!("Advent of Code 2024 - Day {:0>2}", day);
+use registry::DayId;
+
+pub fn start_day(id: DayId) {
+    println!("Advent of Code {} - Day {:02}", id.year, id.day);
 }
 
+pub mod answers;
+pub mod cache;
+pub mod logging;
+pub mod profile;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub use runner::{Runner, Variants};
+pub mod snapshot;
+pub mod vault;
+pub mod viz;
+pub mod watch;
+
 // Additional common functions
 pub mod util {
     pub mod arena_tree;
     pub mod grid;
+    pub mod intervals;
+    pub mod linalg;
+    pub mod memo;
+    pub mod num;
+    pub mod par;
+    pub mod parse;
+    pub mod simulation;
 }
 
 #[cfg(test)]
@@ -14,6 +36,6 @@ mod tests {
 
     #[test]
     fn it_works() {
-        start_day("00");
+        start_day(DayId::new(2024, 1).unwrap());
     }
 }
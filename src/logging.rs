@@ -0,0 +1,23 @@
+use tracing_subscriber::EnvFilter;
+
+/// Sends `tracing` output to stderr.
+///
+/// `RUST_LOG` takes precedence (for example `RUST_LOG=debug` or
+/// `RUST_LOG='info,[dijkstra]=trace'` to trace a single search phase);
+/// otherwise `verbosity` picks the level:
+/// warnings only by default, then info, debug and trace for `-v`, `-vv` and
+/// `-vvv`.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .try_init();
+}
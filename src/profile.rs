@@ -0,0 +1,291 @@
+use crate::util::parse::blocks;
+use anyhow::*;
+use std::any::Any;
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+/// Truncated inputs keep `1 / 2^k` of the real one for `k` from this down
+/// to 0.
+pub const TRUNCATED_STEPS: u32 = 6;
+/// Synthetic inputs start at this size and double at every step.
+pub const SYNTHETIC_START: usize = 64;
+pub const SYNTHETIC_STEPS: u32 = 16;
+
+/// A size is timed at most this many times, keeping the fastest run.
+const REPEATS: usize = 10;
+/// Stop repeating a size once its runs took this long together...
+const REPEAT_MS: f64 = 50.;
+/// ...and stop growing the input once a single run takes this long.
+pub const STEP_LIMIT_MS: f64 = 500.;
+/// Runs faster than this are mostly noise and left out of the fit.
+const MIN_FIT_MS: f64 = 0.02;
+/// How far above a declared exponent the measured one may go, to allow for
+/// caches and noise, with some more for a declared `log n` factor.
+const TOLERANCE: f64 = 0.3;
+const LOG_TOLERANCE: f64 = 0.25;
+
+/// An expected growth rate, written `O(n)`, `O(n log n)`, `O(n^2)` or
+/// `O(1)`, as declared on the `Complexity:` line of a day's header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complexity {
+    pub exponent: f64,
+    pub log: bool,
+}
+
+impl Complexity {
+    /// Whether a measured exponent is within what this allows.
+    pub fn allows(&self, exponent: f64) -> bool {
+        let log = if self.log { LOG_TOLERANCE } else { 0. };
+        exponent <= self.exponent + TOLERANCE + log
+    }
+}
+
+impl FromStr for Complexity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let inner = s
+            .trim()
+            .strip_prefix("O(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(|| anyhow!("`{}` is not of the form O(...)", s))?;
+        let (power, log) = match inner.trim().strip_suffix("log n") {
+            Some(power) => (power.trim(), true),
+            None => (inner.trim(), false),
+        };
+        let exponent = match power {
+            "" if log => 0.,
+            "1" => 0.,
+            "n" => 1.,
+            _ => power
+                .strip_prefix("n^")
+                .and_then(|e| e.parse::<f64>().ok())
+                .ok_or_else(|| anyhow!("cannot read the complexity `{}`", s))?,
+        };
+        Ok(Self { exponent, log })
+    }
+}
+
+impl Display for Complexity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let power = if self.exponent == 0. && self.log {
+            String::new()
+        } else if self.exponent == 0. {
+            String::from("1")
+        } else if self.exponent == 1. {
+            String::from("n")
+        } else {
+            format!("n^{}", self.exponent)
+        };
+        match (power.is_empty(), self.log) {
+            (true, _) => write!(f, "O(log n)"),
+            (false, true) => write!(f, "O({} log n)", power),
+            (false, false) => write!(f, "O({})", power),
+        }
+    }
+}
+
+/// The fastest time of a part on an input of `size`, bytes for a truncated
+/// input or the generator's `n` for a synthetic one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub size: usize,
+    pub ms: f64,
+}
+
+/// Times `run` a few times and keeps the fastest, failing if it does.
+pub fn measure<T, F: FnMut() -> Result<T>>(mut run: F) -> Result<f64> {
+    let mut best = f64::INFINITY;
+    let mut total = 0.;
+    for _ in 0..REPEATS {
+        let start = std::time::Instant::now();
+        // Keeps the optimizer from dropping a solver whose answer is unused.
+        std::hint::black_box(run()?);
+        let ms = start.elapsed().as_secs_f64() * 1000.;
+        best = best.min(ms);
+        total += ms;
+        if total >= REPEAT_MS {
+            break;
+        }
+    }
+    Ok(best)
+}
+
+/// What a caught panic said.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Result::Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| String::from("unknown panic"), |s| s.to_string()),
+    }
+}
+
+/// The exponent `k` of the power law `time ~ size^k` that fits the points
+/// best, by least squares on their logarithms. `None` with fewer than three
+/// points slow enough to measure.
+pub fn fit(points: &[Point]) -> Option<f64> {
+    let logs = points
+        .iter()
+        .filter(|p| p.ms >= MIN_FIT_MS && p.size > 0)
+        .map(|p| ((p.size as f64).ln(), p.ms.ln()))
+        .collect::<Vec<_>>();
+    if logs.len() < 3 {
+        return None;
+    }
+    let n = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (cov, var) = logs.iter().fold((0., 0.), |(cov, var), (x, y)| {
+        (
+            cov + (x - mean_x) * (y - mean_y),
+            var + (x - mean_x) * (x - mean_x),
+        )
+    });
+    (var > 0.).then(|| cov / var)
+}
+
+/// Roughly `1 / 2^k` of `input`. An input of many blocks between blank
+/// lines, such as one machine per block, keeps that share of its blocks.
+/// With only a few, each keeps that share of its lines, so rules and
+/// updates, or wires and gates, keep some of both. A single line keeps that
+/// share of its characters.
+pub fn truncate(input: &str, k: u32) -> String {
+    let keep = |n: usize| n.div_ceil(1 << k).max(1);
+    let text = input.trim_end();
+    if !text.contains('\n') {
+        return text.chars().take(keep(text.chars().count())).collect();
+    }
+    let blocks = blocks(text);
+    let kept = if blocks.len() > 1 << TRUNCATED_STEPS {
+        blocks[..keep(blocks.len())]
+            .iter()
+            .map(|block| block.text.to_owned())
+            .collect::<Vec<_>>()
+    } else {
+        blocks
+            .iter()
+            .map(|block| {
+                let lines = block.lines().map(|line| line.text).collect::<Vec<_>>();
+                lines[..keep(lines.len())].join("\n")
+            })
+            .collect()
+    };
+    kept.join("\n\n") + "\n"
+}
+
+/// A table of how one part scaled with the exponent fitted to it, and a
+/// warning if that is worse than it declared.
+pub fn summary(
+    part: u8,
+    points: &[Point],
+    declared: Option<Complexity>,
+) -> (String, Option<String>) {
+    let mut out = String::new();
+    let _ = writeln!(out, "{:>12} {:>12}", "size", "time");
+    for point in points {
+        let _ = writeln!(out, "{:>12} {:>10.3}ms", point.size, point.ms);
+    }
+    let Some(exponent) = fit(points) else {
+        out += "too fast to fit\n";
+        return (out, None);
+    };
+    let _ = write!(out, "grows as n^{:.2}", exponent);
+    if let Some(declared) = declared {
+        let _ = write!(out, ", declared {}", declared);
+    }
+    out += "\n";
+    let warning = declared.filter(|d| !d.allows(exponent)).map(|declared| {
+        format!(
+            "warning: part {} grows as n^{:.2}, worse than the declared {}",
+            part, exponent, declared
+        )
+    });
+    (out, warning)
+}
+
+/// A small xorshift generator for synthetic inputs, so they are the same on
+/// every run without a dependency.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `range`.
+    pub fn range(&mut self, range: std::ops::Range<u64>) -> u64 {
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complexity() {
+        let parse = |s: &str| s.parse::<Complexity>().unwrap();
+        assert_eq!(
+            parse("O(n log n)"),
+            Complexity {
+                exponent: 1.,
+                log: true
+            }
+        );
+        assert_eq!(parse("O(n^2)").exponent, 2.);
+        assert_eq!(parse("O(1)").exponent, 0.);
+        assert!(parse("O(log n)").log);
+        for s in ["O(1)", "O(log n)", "O(n)", "O(n log n)", "O(n^1.5)"] {
+            assert_eq!(parse(s).to_string(), s);
+        }
+        assert!("O(n!)".parse::<Complexity>().is_err());
+        assert!("n^2".parse::<Complexity>().is_err());
+        assert!(parse("O(n)").allows(1.2));
+        assert!(!parse("O(n)").allows(1.9));
+        assert!(parse("O(n log n)").allows(1.5));
+    }
+
+    #[test]
+    fn test_fit() {
+        let points = (4..10)
+            .map(|k| {
+                let size = 1 << k;
+                Point {
+                    size,
+                    ms: 0.001 * (size * size) as f64,
+                }
+            })
+            .collect::<Vec<_>>();
+        assert!((fit(&points).unwrap() - 2.).abs() < 1e-9);
+        assert_eq!(fit(&points[..2]), None);
+
+        let (summary, warning) = summary(1, &points, Some("O(n log n)".parse().unwrap()));
+        assert!(summary.ends_with("   512    262.144ms\ngrows as n^2.00, declared O(n log n)\n"));
+        assert_eq!(
+            warning.unwrap(),
+            "warning: part 1 grows as n^2.00, worse than the declared O(n log n)"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        let input = "47|53\n97|13\n61|13\n75|29\n\n75,47,61\n97,61,53\n";
+        assert_eq!(truncate(input, 0), input);
+        assert_eq!(truncate(input, 1), "47|53\n97|13\n\n75,47,61\n");
+        assert_eq!(truncate(input, 6), "47|53\n\n75,47,61\n");
+        assert_eq!(truncate("2333133121414131402\n", 2), "23331");
+        let machines = vec!["A: 1\nB: 2"; 100].join("\n\n");
+        assert_eq!(
+            truncate(&machines, 5),
+            ["A: 1\nB: 2"; 4].join("\n\n") + "\n"
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use crate::runner::Record;
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+/// One puzzle of the calendar, written `2024/16`.
+///
+/// Each day is a binary named `<year>-<day>` (`src/bin/2024-16.rs`) whose
+/// input lives in `input/<year>/<day>.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl DayId {
+    pub fn new(year: u16, day: u8) -> Result<Self> {
+        ensure!(year >= 2015, "there is no Advent of Code {}", year);
+        ensure!(
+            (1..=25).contains(&day),
+            "day {} is not between 1 and 25",
+            day
+        );
+        Ok(Self { year, day })
+    }
+
+    pub fn bin_name(&self) -> String {
+        format!("{}-{:02}", self.year, self.day)
+    }
+
+    pub fn source_path(&self) -> PathBuf {
+        PathBuf::from(format!("src/bin/{}.rs", self.bin_name()))
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(format!("input/{}/{:02}.txt", self.year, self.day))
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        PathBuf::from(format!("answers/{}/{:02}.txt", self.year, self.day))
+    }
+
+    /// Where recorded animations go, without the `-<name>.gif` suffix.
+    pub fn viz_path(&self) -> PathBuf {
+        PathBuf::from(format!("viz/{}/{:02}", self.year, self.day))
+    }
+
+    /// The title, techniques and libraries of the day, from its source.
+    pub fn meta(&self) -> Result<Meta> {
+        let path = self.source_path();
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("cannot read {}", path.display()))?;
+        Ok(Meta::parse(&source))
+    }
+
+    /// Builds and runs the day through cargo, returning the records it printed.
+    ///
+    /// The day's example assertions run first, so a failing example shows up
+    /// as an error with the panic message left on stderr.
+    pub fn run(&self, release: bool, extra_args: &[&str]) -> Result<Vec<Record>> {
+        Ok(self.run_to_end(release, extra_args)?.0)
+    }
+
+    /// Like [`DayId::run`], also telling whether the day ran to the end and
+    /// exited successfully.
+    pub(crate) fn run_to_end(
+        &self,
+        release: bool,
+        extra_args: &[&str],
+    ) -> Result<(Vec<Record>, bool)> {
+        let output = self
+            .cargo("run", release)
+            .args(["--", "--format", "json"])
+            .args(extra_args)
+            .stderr(Stdio::inherit())
+            .output()
+            .context("cannot run cargo")?;
+        let records = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.starts_with('{'))
+            .map(|line| serde_json::from_str::<Record>(line).map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(
+            !records.is_empty() || output.status.success(),
+            "{} failed before solving ({})",
+            self,
+            output.status
+        );
+        Ok((records, output.status.success()))
+    }
+
+    /// Builds the day through cargo, compiler messages going to stderr, and
+    /// returns the path of its binary.
+    pub fn build(&self, release: bool) -> Result<PathBuf> {
+        let output = self
+            .cargo("build", release)
+            .arg("--message-format=json-render-diagnostics")
+            .stderr(Stdio::inherit())
+            .output()
+            .context("cannot run cargo")?;
+        ensure!(output.status.success(), "{} does not build", self);
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|message| message["reason"] == "compiler-artifact")
+            .find_map(|message| message["executable"].as_str().map(PathBuf::from))
+            .ok_or_else(|| anyhow!("cargo built no binary for {}", self))
+    }
+
+    /// Runs the day with `args`, such as `--profile`, its report going
+    /// straight to the terminal.
+    pub fn run_attached(&self, release: bool, args: &[&str]) -> Result<()> {
+        let status = self
+            .cargo("run", release)
+            .arg("--")
+            .args(args)
+            .status()
+            .context("cannot run cargo")?;
+        ensure!(status.success(), "{} failed ({})", self, status);
+        Ok(())
+    }
+
+    fn cargo(&self, command: &str, release: bool) -> Command {
+        let mut cmd = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+        cmd.args([command, "--quiet", "--bin", &self.bin_name()]);
+        if release {
+            cmd.arg("--release");
+        }
+        // The days are built with the same features as the tool running them.
+        if cfg!(feature = "parallel") {
+            cmd.args(["--features", "parallel"]);
+        }
+        cmd
+    }
+}
+
+impl Display for DayId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+
+impl FromStr for DayId {
+    type Err = Error;
+
+    /// Accepts `2024/16` as well as the binary name `2024-16`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (year, day) = s
+            .split_once(['/', '-'])
+            .ok_or_else(|| anyhow!("`{}` is not of the form <year>/<day>", s))?;
+        Self::new(
+            year.parse()
+                .with_context(|| format!("bad year in `{}`", s))?,
+            day.parse().with_context(|| format!("bad day in `{}`", s))?,
+        )
+    }
+}
+
+/// What a day says about itself in the `//!` comment its source starts with:
+///
+/// ```text
+/// //! Day 16: Reindeer Maze
+/// //!
+/// //! Techniques: grid, dijkstra, backtracking
+/// //! Complexity: O(n log n), O(n log n)
+/// ```
+///
+/// The complexities are what each part is expected to scale as, in order,
+/// and are checked by `--profile`. The techniques double as tags for `aoc
+/// find`, so reuse the existing names: `grid`, `dijkstra`, `memoization`,
+/// `vm`, `circuit` and so on.
+///
+/// The libraries come from the rest of the source rather than the header, so
+/// they cannot go stale: every crate it has a `use` for, and every `util`
+/// module or `viz` it names. So does whether a part keeps several
+/// implementations, from a `solve_variants` call outside of comments.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Meta {
+    pub title: Option<String>,
+    pub techniques: Vec<String>,
+    pub complexity: Vec<String>,
+    pub uses: Vec<Use>,
+    pub variants: bool,
+}
+
+/// A library a day uses, such as `nom` or `util::grid`, and the line that
+/// first mentions it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Use {
+    pub name: String,
+    pub line: usize,
+}
+
+impl Meta {
+    pub fn parse(source: &str) -> Self {
+        let mut meta = Meta::default();
+        for (i, line) in source.lines().enumerate() {
+            let code = line.split("//").next().unwrap_or_default();
+            meta.variants |= names(code, "solve_variants");
+            for name in used(line.trim()) {
+                if !meta.uses.iter().any(|u| u.name == name) {
+                    meta.uses.push(Use { name, line: i + 1 });
+                }
+            }
+        }
+        for line in source.lines().map_while(|line| line.strip_prefix("//!")) {
+            let line = line.trim();
+            if let Some(list) = line.strip_prefix("Techniques:") {
+                meta.techniques = split_list(list);
+            } else if let Some(list) = line.strip_prefix("Complexity:") {
+                meta.complexity = split_list(list);
+            } else if let Some((_, title)) = line
+                .strip_prefix("Day ")
+                .and_then(|rest| rest.split_once(':'))
+            {
+                meta.title.get_or_insert_with(|| title.trim().to_owned());
+            }
+        }
+        meta
+    }
+
+    /// Whether the day has `tag` among its techniques, ignoring case.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.techniques.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Where the day first uses `name`, which may leave out the `util::` of a
+    /// module.
+    pub fn find_use(&self, name: &str) -> Option<&Use> {
+        self.uses
+            .iter()
+            .find(|u| u.name == name || u.name.strip_prefix("util::") == Some(name))
+    }
+}
+
+/// The libraries one line of a day uses.
+fn used(line: &str) -> Vec<String> {
+    if line.starts_with("//") {
+        return Vec::new();
+    }
+    let module = |path: &str| {
+        let end = path
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(path.len());
+        path[..end].to_owned()
+    };
+    if let Some(path) = line.strip_prefix("use ") {
+        let path = path.strip_prefix("adv_code::").unwrap_or(path);
+        let root = module(path);
+        return match root.as_str() {
+            "" | "std" | "core" | "alloc" | "self" | "super" | "crate" => Vec::new(),
+            "util" => match path.strip_prefix("util::") {
+                Some(rest) => match rest.strip_prefix('{') {
+                    Some(group) => top_level_items(group)
+                        .into_iter()
+                        .map(|item| module(item.trim()))
+                        .filter(|m| !m.is_empty() && m != "self")
+                        .map(|m| format!("util::{}", m))
+                        .collect(),
+                    None => vec![format!("util::{}", module(rest))],
+                },
+                None => Vec::new(),
+            },
+            _ => vec![root],
+        };
+    }
+    ["util::", "viz::"]
+        .iter()
+        .flat_map(|prefix| line.match_indices(prefix))
+        .filter(|&(i, _)| !line[..i].ends_with(|c: char| c.is_alphanumeric() || c == '_'))
+        .filter_map(|(i, prefix)| match prefix {
+            "util::" => Some(module(&line[i + 6..]))
+                .filter(|m| !m.is_empty())
+                .map(|m| format!("util::{}", m)),
+            _ => Some(String::from("viz")),
+        })
+        .collect()
+}
+
+/// Whether `code` has the identifier `name`, not just as part of a longer one.
+fn names(code: &str, name: &str) -> bool {
+    let ident = |c: char| c.is_alphanumeric() || c == '_';
+    code.match_indices(name)
+        .any(|(i, _)| !code[..i].ends_with(ident) && !code[i + name.len()..].starts_with(ident))
+}
+
+/// The items of a `{...}` group of a `use`, after its opening brace, up to
+/// the brace that closes it; nested groups stay inside their item.
+fn top_level_items(group: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in group.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => {
+                items.push(&group[start..i]);
+                return items;
+            }
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&group[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&group[start..]);
+    items
+}
+
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Every day that has a binary under `src/bin`, in calendar order.
+pub fn days() -> Vec<DayId> {
+    let mut days = std::fs::read_dir("src/bin")
+        .into_iter()
+        .flatten()
+        .map_while(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            name.strip_suffix(".rs")?.parse::<DayId>().ok()
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+    days
+}
+
+/// Resolves a command line selection against the registered `days`:
+/// nothing for every day, `2024` for a whole year, `2024/16` for one day, or
+/// a bare `16` for that day of the most recent year.
+pub fn select(days: &[DayId], spec: Option<&str>) -> Result<Vec<DayId>> {
+    let selected = match spec {
+        None | Some("all") => days.to_vec(),
+        Some(s) if s.contains(['/', '-']) => {
+            let id = s.parse::<DayId>()?;
+            days.iter().copied().filter(|d| *d == id).collect()
+        }
+        Some(s) => match s.parse::<u16>()? {
+            year if year >= 2015 => days.iter().copied().filter(|d| d.year == year).collect(),
+            day => {
+                let latest = days.iter().map(|d| d.year).max().unwrap_or_default();
+                days.iter()
+                    .copied()
+                    .filter(|d| d.year == latest && u16::from(d.day) == day)
+                    .collect()
+            }
+        },
+    };
+    ensure!(
+        !selected.is_empty(),
+        "no registered day matches `{}`",
+        spec.unwrap_or("all")
+    );
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_id() {
+        let id = "2024/16".parse::<DayId>().unwrap();
+        assert_eq!(
+            id,
+            DayId {
+                year: 2024,
+                day: 16
+            }
+        );
+        assert_eq!("2024-16".parse::<DayId>().unwrap(), id);
+        assert_eq!(id.to_string(), "2024/16");
+        assert_eq!(id.bin_name(), "2024-16");
+        assert_eq!(id.input_path(), PathBuf::from("input/2024/16.txt"));
+        assert!("2024/26".parse::<DayId>().is_err());
+        assert!("16".parse::<DayId>().is_err());
+        assert!("NN".parse::<DayId>().is_err());
+    }
+
+    #[test]
+    fn test_meta() {
+        let source = "//! Day 16: Reindeer Maze\n//!\n//! Techniques: grid, dijkstra,\n\
+                      //! Complexity: O(n log n), O(n^2)\n\nuse adv_code::*;\n//! Day 1: Not a header\n";
+        assert_eq!(
+            Meta::parse(source),
+            Meta {
+                title: Some(String::from("Reindeer Maze")),
+                techniques: vec![String::from("grid"), String::from("dijkstra")],
+                complexity: vec![String::from("O(n log n)"), String::from("O(n^2)")],
+                uses: Vec::new(),
+                variants: false,
+            }
+        );
+        assert_eq!(Meta::parse("use adv_code::*;\n"), Meta::default());
+
+        let source = "use adv_code::*;\nuse nom::IResult;\nuse nom::multi::many1;\n\
+                      use std::io::BufRead;\nuse util::grid::{Grid, Point};\n\
+                      use adv_code::util::memo::Memo;\n// util::par\n\
+                      let cells = util::grid::Grid::new(v, 1, 1);\n\
+                      let mut recorder = viz::Recorder::new(\"x\", util::simulation::f());\n";
+        let meta = Meta::parse(source);
+        let uses = meta
+            .uses
+            .iter()
+            .map(|u| (u.name.as_str(), u.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            uses,
+            [
+                ("nom", 2),
+                ("util::grid", 5),
+                ("util::memo", 6),
+                ("util::simulation", 9),
+                ("viz", 9)
+            ]
+        );
+        assert_eq!(meta.find_use("grid").map(|u| u.line), Some(5));
+        assert_eq!(meta.find_use("util::memo").map(|u| u.line), Some(6));
+        assert!(meta.find_use("par").is_none());
+
+        assert!(used("use util;").is_empty());
+        assert!(used("use adv_code::util;").is_empty());
+        assert_eq!(
+            used("use util::{grid::{Grid, Point}, parse, self};"),
+            ["util::grid", "util::parse"]
+        );
+        assert!(used("let x = util::{a};").is_empty());
+
+        let variants = |source| Meta::parse(source).variants;
+        assert!(variants(
+            "runner\n    .solve_variants (2, &input, PART2);\n"
+        ));
+        assert!(variants(
+            "Runner::solve_variants(&mut runner, 2, &input, &[]); // part 2\n"
+        ));
+        assert!(!variants("// runner.solve_variants(2, &input, PART2);\n"));
+        assert!(!variants(
+            "runner.solve_parsed(2, &input, part2); // not solve_variants\n"
+        ));
+        assert!(!variants("fn my_solve_variants() {}\n"));
+    }
+
+    #[test]
+    fn test_select() {
+        let days = [
+            DayId::new(2023, 16).unwrap(),
+            DayId::new(2024, 1).unwrap(),
+            DayId::new(2024, 16).unwrap(),
+        ];
+        assert_eq!(select(&days, None).unwrap(), days);
+        assert_eq!(select(&days, Some("2024")).unwrap(), days[1..]);
+        assert_eq!(select(&days, Some("2023/16")).unwrap(), days[..1]);
+        assert_eq!(select(&days, Some("16")).unwrap(), days[2..]);
+        assert!(select(&days, Some("2022")).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use crate::registry::{DayId, Meta};
+use crate::runner::{Record, Status};
+use anyhow::*;
+use base64::Engine;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Everything the report shows about one day.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub id: DayId,
+    pub meta: Meta,
+    pub records: Vec<Record>,
+    /// Why the day has no records, when it failed before solving.
+    pub error: Option<String>,
+    /// Final states of its grids, as written by `--record last`.
+    pub images: Vec<PathBuf>,
+}
+
+impl Entry {
+    fn part(&self, part: u8) -> Option<&Record> {
+        self.records.iter().find(|r| r.part == part)
+    }
+
+    /// The shared parse, counted once, plus the time of every part that
+    /// got to read its input.
+    fn total_ms(&self) -> Option<f64> {
+        let timed = self
+            .records
+            .iter()
+            .filter(|r| r.input_hash.is_some())
+            .collect::<Vec<_>>();
+        let first = timed.first()?;
+        let solve = timed.iter().map(|r| r.elapsed_ms).sum::<f64>();
+        Some(first.parse_ms.unwrap_or_default() + solve)
+    }
+
+    fn peak_kib(&self) -> Option<u64> {
+        self.records.iter().filter_map(|r| r.peak_kib).max()
+    }
+
+    fn errors(&self) -> Vec<&String> {
+        self.error
+            .iter()
+            .chain(self.records.iter().filter_map(|r| r.error.as_ref()))
+            .collect()
+    }
+
+    fn status(&self) -> &'static str {
+        if self.error.is_some() || self.records.iter().any(|r| r.status == Status::Error) {
+            "error"
+        } else if self.records.iter().any(|r| r.status == Status::Wrong) {
+            "wrong"
+        } else {
+            "ok"
+        }
+    }
+}
+
+/// The PNGs `--record last` left for `id`: `viz/<year>/<day>-<name>.png`.
+pub fn images(id: DayId) -> Vec<PathBuf> {
+    let stem = id.viz_path();
+    let prefix = format!(
+        "{}-",
+        stem.file_name().unwrap_or_default().to_string_lossy()
+    );
+    let mut images = std::fs::read_dir(stem.parent().unwrap_or(Path::new(".")))
+        .into_iter()
+        .flatten()
+        .map_while(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name.starts_with(&prefix) && name.ends_with(".png")
+        })
+        .collect::<Vec<_>>();
+    images.sort();
+    images
+}
+
+/// What an image shows, `search` for `viz/2024/16-search.png`.
+fn image_name(path: &Path) -> String {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    stem.split_once('-')
+        .map_or(stem.as_ref(), |(_, name)| name)
+        .to_owned()
+}
+
+fn answer(record: Option<&Record>) -> String {
+    match record {
+        Some(Record {
+            answer: Some(answer),
+            status: Status::Wrong,
+            expected,
+            ..
+        }) => format!(
+            "{} (wrong, expected {})",
+            answer,
+            expected.as_deref().unwrap_or_default()
+        ),
+        Some(Record {
+            answer: Some(answer),
+            ..
+        }) => answer.clone(),
+        Some(_) => String::from("error"),
+        None => String::from("-"),
+    }
+}
+
+fn duration(ms: Option<f64>) -> String {
+    match ms {
+        Some(ms) if ms >= 1000. => format!("{:.2}s", ms / 1000.),
+        Some(ms) => format!("{:.3}ms", ms),
+        None => String::from("-"),
+    }
+}
+
+fn memory(kib: Option<u64>) -> String {
+    match kib {
+        Some(kib) if kib >= 1024 => format!("{:.1} MiB", kib as f64 / 1024.),
+        Some(kib) => format!("{} KiB", kib),
+        None => String::from("-"),
+    }
+}
+
+fn title(entry: &Entry) -> &str {
+    entry.meta.title.as_deref().unwrap_or_default()
+}
+
+/// A Markdown table of every day, with links relative to the repository
+/// root so it can stand in for the README.
+pub fn markdown(entries: &[Entry]) -> String {
+    let cell = |s: &str| s.replace('|', "\\|");
+    let mut out = String::from("# Advent of Code\n\n");
+    out += "| Day | Title | Part 1 | Part 2 | Time | Memory | Techniques |\n";
+    out += "|-----|-------|--------|--------|-----:|-------:|------------|\n";
+    for entry in entries {
+        let _ = writeln!(
+            out,
+            "| [{}]({}) | {} | {} | {} | {} | {} | {} |",
+            entry.id,
+            entry.id.source_path().display(),
+            cell(title(entry)),
+            cell(&answer(entry.part(1))),
+            cell(&answer(entry.part(2))),
+            duration(entry.total_ms()),
+            memory(entry.peak_kib()),
+            cell(&entry.meta.techniques.join(", ")),
+        );
+    }
+    out += "\nGenerated by `cargo run --release --bin aoc -- report`.\n";
+    out
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2em auto; max-width: 70em; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { padding: .3em .6em; border-bottom: 1px solid #ddd; text-align: left; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+tr.wrong td { background: #fff4d6; }
+tr.error td { background: #fde2e2; }
+code { font-size: .9em; }
+figure { display: inline-block; margin: 0 1em 1em 0; vertical-align: top; }
+img { image-rendering: pixelated; max-width: 100%; border: 1px solid #ccc; }
+";
+
+/// A single HTML page with the same table, the errors, and the final state
+/// of every grid day embedded as PNG data.
+pub fn html(entries: &[Entry]) -> Result<String> {
+    let mut out =
+        String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(
+        out,
+        "<title>Advent of Code</title>\n<style>\n{}</style>\n</head>\n<body>\n<h1>Advent of Code</h1>",
+        STYLE
+    );
+    out += "<table>\n<tr><th>Day</th><th>Title</th><th>Part 1</th><th>Part 2</th>\
+            <th>Time</th><th>Memory</th><th>Techniques</th></tr>\n";
+    for entry in entries {
+        let _ = writeln!(
+            out,
+            "<tr class=\"{}\"><td>{}</td><td>{}</td><td><code>{}</code></td>\
+             <td><code>{}</code></td><td class=\"num\">{}</td><td class=\"num\">{}</td><td>{}</td></tr>",
+            entry.status(),
+            if entry.errors().is_empty() && entry.images.is_empty() {
+                entry.id.to_string()
+            } else {
+                format!("<a href=\"#{}\">{}</a>", entry.id.bin_name(), entry.id)
+            },
+            escape(title(entry)),
+            escape(&answer(entry.part(1))),
+            escape(&answer(entry.part(2))),
+            duration(entry.total_ms()),
+            memory(entry.peak_kib()),
+            escape(&entry.meta.techniques.join(", ")),
+        );
+    }
+    out += "</table>\n";
+
+    for entry in entries {
+        let errors = entry.errors();
+        if errors.is_empty() && entry.images.is_empty() {
+            continue;
+        }
+        let _ = writeln!(
+            out,
+            "<h2 id=\"{}\">{} {}</h2>",
+            entry.id.bin_name(),
+            entry.id,
+            escape(title(entry))
+        );
+        for error in errors {
+            let _ = writeln!(out, "<pre>{}</pre>", escape(error));
+        }
+        for path in &entry.images {
+            let png =
+                std::fs::read(path).with_context(|| format!("cannot read {}", path.display()))?;
+            let _ = writeln!(
+                out,
+                "<figure><img src=\"data:image/png;base64,{}\" alt=\"{}\"><figcaption>{}</figcaption></figure>",
+                base64::engine::general_purpose::STANDARD.encode(png),
+                escape(&image_name(path)),
+                escape(&image_name(path)),
+            );
+        }
+    }
+    out += "</body>\n</html>\n";
+    Ok(out)
+}
+
+/// Writes `index.html` and `README.md` into `dir`, returning their paths.
+pub fn write(dir: &Path, entries: &[Entry]) -> Result<[PathBuf; 2]> {
+    std::fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    let html_path = dir.join("index.html");
+    let markdown_path = dir.join("README.md");
+    std::fs::write(&html_path, html(entries)?)
+        .with_context(|| format!("cannot write {}", html_path.display()))?;
+    std::fs::write(&markdown_path, markdown(entries))
+        .with_context(|| format!("cannot write {}", markdown_path.display()))?;
+    Ok([html_path, markdown_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u8, answer: &str, status: Status) -> Record {
+        Record {
+            year: 2024,
+            day: 16,
+            part,
+            answer: Some(answer.to_owned()),
+            expected: (status == Status::Wrong).then(|| String::from("64")),
+            status,
+            input_hash: Some(String::from("ab")),
+            parse_ms: Some(0.5),
+            elapsed_ms: 1.25,
+            serial_ms: None,
+            peak_kib: Some(2048 * part as u64),
+            error: None,
+            cached: false,
+        }
+    }
+
+    fn entries(images: Vec<PathBuf>) -> Vec<Entry> {
+        let meta = Meta {
+            title: Some(String::from("Reindeer Maze")),
+            techniques: vec![String::from("grid"), String::from("dijkstra")],
+            complexity: Vec::new(),
+            uses: Vec::new(),
+            variants: false,
+        };
+        vec![
+            Entry {
+                id: DayId::new(2024, 16).unwrap(),
+                meta,
+                records: vec![
+                    record(1, "11048", Status::Ok),
+                    record(2, "6|5", Status::Wrong),
+                ],
+                error: None,
+                images,
+            },
+            Entry {
+                id: DayId::new(2024, 17).unwrap(),
+                meta: Meta::default(),
+                records: Vec::new(),
+                error: Some(String::from("left: <19>")),
+                images: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_markdown() {
+        let markdown = markdown(&entries(Vec::new()));
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[4],
+            "| [2024/16](src/bin/2024-16.rs) | Reindeer Maze | 11048 | 6\\|5 (wrong, expected 64) \
+             | 3.000ms | 4.0 MiB | grid, dijkstra |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [2024/17](src/bin/2024-17.rs) |  | - | - | - | - |  |"
+        );
+    }
+
+    #[test]
+    fn test_html() {
+        let dir = std::env::temp_dir().join(format!("report-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let image = dir.join("16-search.png");
+        std::fs::write(&image, b"\x89PNG").unwrap();
+
+        let html = html(&entries(vec![image])).unwrap();
+        assert!(html.contains("<tr class=\"wrong\"><td><a href=\"#2024-16\">2024/16</a>"));
+        assert!(html.contains("<tr class=\"error\">"));
+        assert!(html.contains("<pre>left: &lt;19&gt;</pre>"));
+        assert!(html.contains("<img src=\"data:image/png;base64,iVBORw==\" alt=\"search\">"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
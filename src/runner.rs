@@ -0,0 +1,825 @@
+use crate::answers::Answers;
+use crate::profile::{self, Complexity, Point};
+use crate::registry::DayId;
+use crate::util::par;
+use crate::vault;
+use crate::viz::Export;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Write};
+use std::io::Cursor;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::str::FromStr;
+use std::time::Instant;
+use tracing::{debug, info, info_span, Span};
+
+/// How a day reports its answers on stdout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable `=== Part N ===` / `Result = ...` blocks.
+    #[default]
+    Text,
+    /// One JSON object per line, one line per part.
+    Json,
+    /// A header row followed by one row per part.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("unknown format `{}`, expected one of text, json, csv", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    /// Solved, but differs from the accepted answer.
+    Wrong,
+    Error,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Outcome of solving one part against the real input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    pub input_hash: Option<String>,
+    /// Time spent in the day's shared `parse`, if it has one.
+    #[serde(default)]
+    pub parse_ms: Option<f64>,
+    /// Time spent solving the part, not counting `parse_ms`.
+    pub elapsed_ms: f64,
+    /// Time spent solving the part on a single thread, with `--speedup`.
+    #[serde(default)]
+    pub serial_ms: Option<f64>,
+    /// Peak resident memory of the day's process once the part is solved,
+    /// where the platform reports it.
+    #[serde(default)]
+    pub peak_kib: Option<u64>,
+    pub error: Option<String>,
+    /// Replayed from `.aoc-cache` by `aoc run` instead of solved, so the
+    /// timings are those of the run that stored it.
+    #[serde(default)]
+    pub cached: bool,
+}
+
+pub const CSV_HEADER: &str =
+    "year,day,part,answer,expected,status,input_hash,parse_ms,elapsed_ms,serial_ms,peak_kib,error,cached";
+
+impl Record {
+    pub fn to_csv(&self) -> String {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(self.answer.as_deref().unwrap_or_default()),
+            csv_field(self.expected.as_deref().unwrap_or_default()),
+            self.status.to_string(),
+            self.input_hash.clone().unwrap_or_default(),
+            self.parse_ms
+                .map(|ms| format!("{:.3}", ms))
+                .unwrap_or_default(),
+            format!("{:.3}", self.elapsed_ms),
+            self.serial_ms
+                .map(|ms| format!("{:.3}", ms))
+                .unwrap_or_default(),
+            self.peak_kib.map(|kib| kib.to_string()).unwrap_or_default(),
+            csv_field(self.error.as_deref().unwrap_or_default()),
+            self.cached.to_string(),
+        ]
+        .join(",")
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// The high-water mark of resident memory, `VmHWM` in `/proc/self/status`.
+/// Only Linux has it; elsewhere this is `None`.
+pub fn peak_memory_kib() -> Option<u64> {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| vm_hwm(&status))
+}
+
+fn vm_hwm(status: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()
+}
+
+pub fn hash_input(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .fold(String::new(), |mut output, b| {
+            let _ = write!(output, "{:02x}", b);
+            output
+        })
+}
+
+/// Command line options understood by every day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub format: Format,
+    /// Number of `-v` flags; see [`crate::logging::init`].
+    pub verbosity: u8,
+    /// Set by `--record gif|png|ppm|play|last` or `--play`; see
+    /// [`crate::viz::Recorder`].
+    pub record: Option<Export>,
+    /// Set by `--fps N`: how many frames `--play` shows a second, instead of
+    /// the speed each recorder sets with `delay`.
+    pub fps: Option<u32>,
+    /// Set by `--profile`: time each part on growing inputs instead of
+    /// solving it; see [`Runner::synthetic`].
+    pub profile: bool,
+    /// Set by `--speedup`: solve each part once more on a single thread and
+    /// report how much faster the parallel run was; see [`crate::util::par`].
+    pub speedup: bool,
+    /// Set by `--compare`: run every variant of the parts that have several
+    /// instead of solving; see [`Runner::solve_variants`].
+    pub compare: bool,
+}
+
+impl Options {
+    /// Parses `--format json|csv|text`, `--record gif|png|ppm|play|last`, `--play`,
+    /// `--fps N`, `--profile`, `--speedup`, `--compare` and `-v`/`-vv`/`-vvv`.
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            let value = || {
+                inline
+                    .or_else(|| args.next())
+                    .ok_or_else(|| anyhow!("{} needs a value", flag))
+            };
+            match flag {
+                "--format" => options.format = value()?.parse()?,
+                "--record" => options.record = Some(value()?.parse()?),
+                "--play" => options.record = Some(Export::Play),
+                "--fps" => {
+                    let fps = value()?;
+                    options.fps = Some(
+                        fps.parse()
+                            .ok()
+                            .filter(|&fps| fps > 0)
+                            .ok_or_else(|| anyhow!("bad frame rate `{}`", fps))?,
+                    )
+                }
+                "--profile" => options.profile = true,
+                "--speedup" => options.speedup = true,
+                "--compare" => options.compare = true,
+                _ if arg == "--verbose" => options.verbosity += 1,
+                _ if arg.len() > 1
+                    && arg
+                        .strip_prefix('-')
+                        .is_some_and(|v| v.bytes().all(|b| b == b'v')) =>
+                {
+                    options.verbosity += arg.len() as u8 - 1
+                }
+                _ => bail!("unknown argument `{}`", arg),
+            }
+        }
+        ensure!(
+            options.fps.is_none() || options.record == Some(Export::Play),
+            "--fps only applies to --play"
+        );
+        Ok(options)
+    }
+}
+
+/// Named implementations of one part, the first being the one that solves it
+/// normally:
+///
+/// ```ignore
+/// const PART2: &Variants<Grid, usize> = &[("walk", part2), ("jumps", part2_jumps)];
+/// runner.solve_variants(2, &input, PART2);
+/// ```
+pub type Variants<P, T> = [(&'static str, fn(&P) -> Result<T>)];
+
+/// What was timed besides the solver itself.
+#[derive(Debug, Default, Clone, Copy)]
+struct Timings {
+    parse_ms: Option<f64>,
+    serial_ms: Option<f64>,
+}
+
+/// Parses the input scaled to profiling step `k`, giving its size, or
+/// `None` past the last step.
+type Scaled<P> = Box<dyn Fn(u32) -> Option<(usize, Result<P>)>>;
+
+/// The day's input after [`Runner::parse`], shared by its parts.
+pub struct Input<P> {
+    parsed: Result<P>,
+    hash: Option<String>,
+    parse_ms: f64,
+    /// Only when profiling.
+    scaled: Option<Scaled<P>>,
+}
+
+/// Drives the parts of a single day: reads the input, times the solver and
+/// prints the answers in the requested [`Format`].
+///
+/// Everything a runner prints goes to stdout. Solvers report progress through
+/// `tracing`, which logs to stderr and is silent unless asked for, so
+/// `--format json|csv` stays parseable.
+pub struct Runner {
+    id: DayId,
+    answers: Answers,
+    format: Format,
+    records: Vec<Record>,
+    span: Span,
+    /// Whether a section has been printed yet, in text mode.
+    printed: bool,
+    profile: bool,
+    synthetic: Option<fn(usize) -> String>,
+    speedup: bool,
+    compare: bool,
+}
+
+impl Runner {
+    /// Builds a runner from the process arguments and sets up logging.
+    pub fn new(year: &str, day: &str) -> Result<Self> {
+        let id = DayId::new(year.parse()?, day.parse()?)?;
+        let options = Options::from_args(std::env::args().skip(1))?;
+        crate::logging::init(options.verbosity);
+        if let Some(export) = options.record {
+            crate::viz::enable(id, export, options.fps);
+        }
+        Self::with_options(id, &options)
+    }
+
+    pub fn with_options(id: DayId, options: &Options) -> Result<Self> {
+        let answers = Answers::load(id)?;
+        match options.format {
+            Format::Text => crate::start_day(id),
+            Format::Csv => println!("{}", CSV_HEADER),
+            Format::Json => (),
+        }
+        if options.speedup && !cfg!(feature = "parallel") {
+            eprintln!("warning: built without the parallel feature, so both runs are serial");
+        }
+        Ok(Self {
+            id,
+            answers,
+            format: options.format,
+            records: Vec::new(),
+            span: info_span!("day", %id),
+            printed: false,
+            profile: options.profile,
+            synthetic: None,
+            speedup: options.speedup,
+            compare: options.compare,
+        })
+    }
+
+    /// Gives `--profile` a generator of inputs of any size `n`, to use
+    /// instead of truncating the real input. Call it before
+    /// [`Runner::parse`].
+    pub fn synthetic(&mut self, generate: fn(usize) -> String) {
+        self.synthetic = Some(generate);
+    }
+
+    fn section(&mut self, title: &str) {
+        if self.format == Format::Text {
+            if self.printed {
+                println!();
+            }
+            println!("=== {} ===", title);
+        }
+        self.printed = true;
+    }
+
+    fn read_input(&self) -> Result<(Vec<u8>, String)> {
+        let input_path = self.id.input_path();
+        let bytes = vault::read(&input_path)?
+            .ok_or_else(|| anyhow!("cannot read {}: no such file", input_path.display()))?;
+        let hash = hash_input(&bytes);
+        Ok((bytes, hash))
+    }
+
+    /// Reads the input and parses it once for every part, timing the parse
+    /// on its own. A failure is kept and reported by each part that uses it.
+    ///
+    /// With `--profile`, nothing is parsed yet: the parts parse the input
+    /// themselves at every size they are timed on.
+    pub fn parse<P, F>(&mut self, parser: F) -> Input<P>
+    where
+        P: 'static,
+        F: Fn(Cursor<Vec<u8>>) -> Result<P> + 'static,
+    {
+        let _parse = info_span!(parent: &self.span, "parse").entered();
+        if self.profile {
+            return self.scale(parser);
+        }
+        let (bytes, hash) = match self.read_input() {
+            Result::Ok(input) => input,
+            Err(e) => {
+                return Input {
+                    parsed: Err(e),
+                    hash: None,
+                    parse_ms: 0.,
+                    scaled: None,
+                }
+            }
+        };
+        let start = Instant::now();
+        let parsed = parser(Cursor::new(bytes)).context("cannot parse input");
+        let parse_ms = start.elapsed().as_secs_f64() * 1000.;
+        info!(elapsed_ms = parse_ms, "parsed");
+        if parsed.is_ok() {
+            self.section("Parse");
+            if self.format == Format::Text {
+                println!("took {:.3}ms.", parse_ms);
+            }
+        }
+        Input {
+            parsed,
+            hash: Some(hash),
+            parse_ms,
+            scaled: None,
+        }
+    }
+
+    fn scale<P, F>(&self, parser: F) -> Input<P>
+    where
+        P: 'static,
+        F: Fn(Cursor<Vec<u8>>) -> Result<P> + 'static,
+    {
+        // Truncated inputs can make a parser panic as well as fail.
+        let parse = move |text: String| {
+            catch_unwind(AssertUnwindSafe(|| parser(Cursor::new(text.into_bytes()))))
+                .unwrap_or_else(|panic| Err(anyhow!(profile::panic_message(panic))))
+        };
+        let scaled: Result<Scaled<P>> = match self.synthetic {
+            Some(generate) => Ok(Box::new(move |k| {
+                let n = profile::SYNTHETIC_START << k;
+                (k <= profile::SYNTHETIC_STEPS).then(|| (n, parse(generate(n))))
+            })),
+            None => self.read_input().map(|(bytes, _)| -> Scaled<P> {
+                let text = String::from_utf8_lossy(&bytes).into_owned();
+                Box::new(move |k| {
+                    let truncated =
+                        profile::truncate(&text, profile::TRUNCATED_STEPS.checked_sub(k)?);
+                    Some((truncated.len(), parse(truncated)))
+                })
+            }),
+        };
+        match scaled {
+            Result::Ok(scaled) => Input {
+                parsed: Err(anyhow!("not parsed when profiling")),
+                hash: None,
+                parse_ms: 0.,
+                scaled: Some(scaled),
+            },
+            Err(e) => Input {
+                parsed: Err(e),
+                hash: None,
+                parse_ms: 0.,
+                scaled: None,
+            },
+        }
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Solves `part` against the day's input file and reports the answer,
+    /// checking it against the accepted one when there is one.
+    ///
+    /// Failures are recorded rather than returned so the remaining parts
+    /// still run; [`Runner::finish`] turns them into the exit status.
+    pub fn solve<T, F>(&mut self, part: u8, solver: F)
+    where
+        T: Display,
+        F: FnOnce(Cursor<Vec<u8>>) -> Result<T>,
+    {
+        if self.compare {
+            return;
+        }
+        match self.read_input() {
+            Result::Ok((bytes, hash)) => {
+                self.run_part(part, Some(hash), Timings::default(), || {
+                    solver(Cursor::new(bytes))
+                })
+            }
+            Err(e) => self.run_part(part, None, Timings::default(), || Err::<T, _>(e)),
+        }
+    }
+
+    /// Solves a part from the input shared through [`Runner::parse`].
+    pub fn solve_parsed<P, T, F>(&mut self, part: u8, input: &Input<P>, solver: F)
+    where
+        T: Display,
+        F: Fn(&P) -> Result<T>,
+    {
+        if let Some(scaled) = &input.scaled {
+            return self.profile_part(part, scaled, solver);
+        }
+        if self.compare {
+            return;
+        }
+        let parse_ms = input.hash.as_ref().map(|_| input.parse_ms);
+        let serial_ms = match &input.parsed {
+            Result::Ok(parsed) if self.speedup => Some(self.time_serial(part, || solver(parsed))),
+            _ => None,
+        };
+        let timings = Timings {
+            parse_ms,
+            serial_ms,
+        };
+        self.run_part(part, input.hash.clone(), timings, || match &input.parsed {
+            Result::Ok(parsed) => solver(parsed),
+            Err(e) => Err(anyhow!("{:#}", e)),
+        })
+    }
+
+    /// Times a run of `solver` on a single thread, whatever it returns.
+    fn time_serial<T, F: FnOnce() -> Result<T>>(&self, part: u8, solver: F) -> f64 {
+        let _serial = info_span!(parent: &self.span, "serial", part).entered();
+        let start = Instant::now();
+        let _ = std::hint::black_box(par::serial(solver));
+        let serial_ms = start.elapsed().as_secs_f64() * 1000.;
+        info!(elapsed_ms = serial_ms, "solved serially");
+        serial_ms
+    }
+
+    fn run_part<T, F>(&mut self, part: u8, hash: Option<String>, timings: Timings, solver: F)
+    where
+        T: Display,
+        F: FnOnce() -> Result<T>,
+    {
+        self.section(&format!("Part {}", part));
+
+        let _part = info_span!(parent: &self.span, "part", part).entered();
+        let mut record = Record {
+            year: self.id.year,
+            day: self.id.day,
+            part,
+            answer: None,
+            expected: self.answers.get(part).map(String::from),
+            status: Status::Ok,
+            input_hash: hash,
+            parse_ms: timings.parse_ms,
+            elapsed_ms: 0.,
+            serial_ms: timings.serial_ms,
+            peak_kib: None,
+            error: None,
+            cached: false,
+        };
+        let start = Instant::now();
+        let result = crate::viz::solving(solver);
+        if record.input_hash.is_some() {
+            record.elapsed_ms = start.elapsed().as_secs_f64() * 1000.;
+            record.peak_kib = peak_memory_kib();
+            info!(
+                elapsed_ms = record.elapsed_ms,
+                peak_kib = record.peak_kib,
+                "solved"
+            );
+        }
+        match result {
+            Result::Ok(answer) => {
+                let answer = answer.to_string();
+                if record.expected.as_ref().is_some_and(|e| *e != answer) {
+                    record.status = Status::Wrong;
+                }
+                record.answer = Some(answer);
+            }
+            Err(e) => {
+                record.status = Status::Error;
+                record.error = Some(format!("{:#}", e));
+            }
+        }
+
+        match self.format {
+            Format::Text => match &record.answer {
+                Some(answer) => {
+                    println!("took {:.3}ms.", record.elapsed_ms);
+                    if let Some(serial_ms) = record.serial_ms {
+                        println!(
+                            "serially {:.3}ms, {:.2}x as fast on {} threads.",
+                            serial_ms,
+                            serial_ms / record.elapsed_ms,
+                            par::threads()
+                        );
+                    }
+                    println!("Result = {}", answer);
+                    if record.status == Status::Wrong {
+                        eprintln!(
+                            "Expected = {}",
+                            record.expected.as_deref().unwrap_or_default()
+                        );
+                    }
+                }
+                None => eprintln!("Error = {}", record.error.as_deref().unwrap_or_default()),
+            },
+            Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+        self.records.push(record);
+    }
+
+    /// Times `solver` on ever larger inputs until they run out or one run
+    /// takes too long, then fits how the time grows and warns if that is
+    /// worse than the `Complexity:` the day declares for the part.
+    fn profile_part<P, T, F>(&mut self, part: u8, scaled: &Scaled<P>, solver: F)
+    where
+        F: Fn(&P) -> Result<T>,
+    {
+        self.section(&format!("Part {} profile", part));
+        let _part = info_span!(parent: &self.span, "profile", part).entered();
+
+        // Solvers are free to panic on a truncated input; keep that quiet.
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+        let mut points = Vec::new();
+        for k in 0.. {
+            let Some((size, parsed)) = scaled(k) else {
+                break;
+            };
+            let ms = parsed.and_then(|parsed| {
+                catch_unwind(AssertUnwindSafe(|| profile::measure(|| solver(&parsed))))
+                    .unwrap_or_else(|panic| Err(anyhow!(profile::panic_message(panic))))
+            });
+            match ms {
+                Result::Ok(ms) => {
+                    debug!(size, ms, "timed");
+                    points.push(Point { size, ms });
+                    if ms > profile::STEP_LIMIT_MS {
+                        break;
+                    }
+                }
+                Err(e) => debug!(size, "skipped: {:#}", e),
+            }
+        }
+        std::panic::set_hook(hook);
+
+        let declared = self.declared_complexity(part);
+        let (summary, warning) = profile::summary(part, &points, declared);
+        print!("{}", summary);
+        if let Some(warning) = warning {
+            eprintln!("{}", warning);
+        }
+    }
+
+    /// The complexity the day's header declares for `part`, if any.
+    fn declared_complexity(&self, part: u8) -> Option<Complexity> {
+        let meta = self.id.meta().ok()?;
+        let declared = meta.complexity.get(usize::from(part) - 1)?;
+        match declared.parse() {
+            Result::Ok(complexity) => Some(complexity),
+            Err(e) => {
+                eprintln!("warning: {:#} in {}", e, self.id.source_path().display());
+                None
+            }
+        }
+    }
+
+    /// Solves a part with the first of its `variants`, or with `--compare`
+    /// runs them all on the input, times each and checks they agree.
+    pub fn solve_variants<P, T>(&mut self, part: u8, input: &Input<P>, variants: &Variants<P, T>)
+    where
+        T: Display + PartialEq,
+    {
+        let Some(&(_, primary)) = variants.first() else {
+            return;
+        };
+        if !self.compare || input.scaled.is_some() {
+            return self.solve_parsed(part, input, primary);
+        }
+        self.section(&format!("Part {} variants", part));
+        let _part = info_span!(parent: &self.span, "compare", part).entered();
+
+        let parsed = input.parsed.as_ref().map_err(|e| anyhow!("{:#}", e));
+        let mut results = Vec::new();
+        for &(name, solver) in variants {
+            let result = parsed
+                .as_ref()
+                .map_err(|e| anyhow!("{:#}", e))
+                .and_then(|parsed| {
+                    let answer = solver(parsed)?;
+                    let ms = profile::measure(|| solver(parsed))?;
+                    Ok((answer, ms))
+                });
+            debug!(name, ok = result.is_ok(), "compared");
+            results.push((name, result));
+        }
+
+        let mut record = Record {
+            year: self.id.year,
+            day: self.id.day,
+            part,
+            answer: None,
+            expected: self.answers.get(part).map(String::from),
+            status: Status::Ok,
+            input_hash: input.hash.clone(),
+            parse_ms: None,
+            elapsed_ms: 0.,
+            serial_ms: None,
+            peak_kib: None,
+            error: None,
+            cached: false,
+        };
+        let answers = results
+            .iter()
+            .filter_map(|(name, r)| r.as_ref().ok().map(|(answer, _)| (name, answer)))
+            .collect::<Vec<_>>();
+        if let Some(&(_, first)) = answers.first() {
+            record.answer = Some(first.to_string());
+        }
+        if let Some(Result::Ok((_, ms))) = results.first().map(|(_, r)| r) {
+            record.elapsed_ms = *ms;
+        }
+        if answers.len() < results.len() {
+            record.status = Status::Error;
+            record.error = Some(String::from("some variants failed"));
+        } else if let Some((name, answer)) = answers.iter().find(|(_, a)| *a != answers[0].1) {
+            let message = format!(
+                "{} gives {} but {} gives {}",
+                name, answer, answers[0].0, answers[0].1
+            );
+            eprintln!("warning: part {} variants disagree: {}", part, message);
+            record.status = Status::Error;
+            record.error = Some(message);
+        } else if record.expected.is_some() && record.expected != record.answer {
+            record.status = Status::Wrong;
+        }
+
+        match self.format {
+            Format::Text => {
+                let fastest = results
+                    .iter()
+                    .filter_map(|(_, r)| r.as_ref().ok().map(|&(_, ms)| ms))
+                    .fold(f64::INFINITY, f64::min);
+                for (name, result) in &results {
+                    match result {
+                        Result::Ok((answer, ms)) => println!(
+                            "{:<16} {:<20} {:>10.3}ms {:>7.2}x",
+                            name,
+                            answer.to_string(),
+                            ms,
+                            ms / fastest
+                        ),
+                        Err(e) => println!("{:<16} error: {:#}", name, e),
+                    }
+                }
+                if record.status == Status::Wrong {
+                    eprintln!(
+                        "Expected = {}",
+                        record.expected.as_deref().unwrap_or_default()
+                    );
+                }
+            }
+            Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+        self.records.push(record);
+    }
+
+    /// Fails if any part could not be solved or disagrees with its accepted
+    /// answer.
+    pub fn finish(self) -> Result<()> {
+        let failed = self
+            .records
+            .iter()
+            .filter(|r| r.status != Status::Ok)
+            .count();
+        ensure!(failed == 0, "{}: {} part(s) failed", self.id, failed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_options_from_args() {
+        let options = Options::from_args(args("--format json -vv")).unwrap();
+        assert_eq!(
+            options,
+            Options {
+                format: Format::Json,
+                verbosity: 2,
+                record: None,
+                fps: None,
+                profile: false,
+                speedup: false,
+                compare: false,
+            }
+        );
+        let options = Options::from_args(args("--record gif")).unwrap();
+        assert_eq!(options.record, Some(Export::Gif));
+        let options = Options::from_args(args("--play --fps 30")).unwrap();
+        assert_eq!(
+            (options.record, options.fps),
+            (Some(Export::Play), Some(30))
+        );
+        assert!(Options::from_args(args("--play --fps 0")).is_err());
+        assert!(Options::from_args(args("--record gif --fps 30")).is_err());
+        assert!(Options::from_args(args("--profile")).unwrap().profile);
+        assert!(Options::from_args(args("--speedup")).unwrap().speedup);
+        assert!(Options::from_args(args("--compare")).unwrap().compare);
+        let options = Options::from_args(args("--format=csv -v --verbose")).unwrap();
+        assert_eq!(options.format, Format::Csv);
+        assert_eq!(options.verbosity, 2);
+        assert!(Options::from_args(args("--format xml")).is_err());
+        assert!(Options::from_args(args("--format")).is_err());
+        assert!(Options::from_args(args("-")).is_err());
+        assert!(Options::from_args(args("-x")).is_err());
+    }
+
+    #[test]
+    fn test_record_csv() {
+        let mut record = Record {
+            year: 2024,
+            day: 17,
+            part: 1,
+            answer: Some("4,6,3".into()),
+            expected: None,
+            status: Status::Ok,
+            input_hash: Some("ab".into()),
+            parse_ms: Some(0.25),
+            elapsed_ms: 1.5,
+            serial_ms: Some(6.),
+            peak_kib: Some(2048),
+            error: None,
+            cached: false,
+        };
+        assert_eq!(CSV_HEADER.split(',').count(), 13);
+        assert_eq!(
+            record.to_csv(),
+            "2024,17,1,\"4,6,3\",,ok,ab,0.250,1.500,6.000,2048,,false"
+        );
+        record.parse_ms = None;
+        record.serial_ms = None;
+        record.peak_kib = None;
+        record.cached = true;
+        assert_eq!(record.to_csv(), "2024,17,1,\"4,6,3\",,ok,ab,,1.500,,,,true");
+    }
+
+    #[test]
+    fn test_vm_hwm() {
+        let status = "Name:\t2024-16\nVmPeak:\t  20480 kB\nVmHWM:\t    8192 kB\nVmRSS:\t 4096 kB\n";
+        assert_eq!(vm_hwm(status), Some(8192));
+        assert_eq!(vm_hwm("Name:\tfoo\n"), None);
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("123"), "123");
+        assert_eq!(csv_field("4,6,3"), "\"4,6,3\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_hash_input() {
+        assert_eq!(
+            hash_input(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Golden files for outputs that are easier to look at than to spell out in
+//! an `assert_eq!`, such as a rendered grid.
+//!
+//! [`assert_snapshot`] compares a rendering with `snapshots/<name>.txt` and
+//! fails with a line diff when they differ. Running the tests with
+//! `UPDATE_SNAPSHOTS=1` writes the new renderings instead, to be reviewed
+//! and committed like any other change.
+
+use anyhow::*;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+pub const SNAPSHOT_DIR: &str = "snapshots";
+/// Set to anything but `0` to accept the new renderings.
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+/// Unchanged lines shown around each change in a diff.
+const CONTEXT: usize = 2;
+
+/// Where the snapshot `name` is kept; `name` may contain `/`.
+pub fn path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(SNAPSHOT_DIR)
+        .join(format!("{}.txt", name))
+}
+
+fn updating() -> bool {
+    std::env::var(UPDATE_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Panics with a diff unless `actual` matches the snapshot `name`, or
+/// writes it with `UPDATE_SNAPSHOTS=1`.
+#[track_caller]
+pub fn assert_snapshot(name: &str, actual: &str) {
+    if let Err(e) = check(&path(name), actual, updating()) {
+        panic!("{:#}", e);
+    }
+}
+
+/// Compares `actual` with the file at `path`, or writes it there when
+/// `update` is set.
+pub fn check(path: &Path, actual: &str, update: bool) -> Result<()> {
+    if update {
+        if std::fs::read_to_string(path).is_ok_and(|expected| expected == actual) {
+            return Ok(());
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, actual).with_context(|| format!("cannot write {}", path.display()))?;
+        eprintln!("updated {}", path.display());
+        return Ok(());
+    }
+    let expected = match std::fs::read_to_string(path) {
+        Result::Ok(expected) => expected,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => bail!(
+            "no snapshot {} yet; run with {}=1 to record this:\n{}",
+            path.display(),
+            UPDATE_ENV,
+            actual
+        ),
+        Err(e) => return Err(e).with_context(|| format!("cannot read {}", path.display())),
+    };
+    ensure!(
+        expected == actual,
+        "snapshot {} differs; run with {}=1 to accept it\n{}",
+        path.display(),
+        UPDATE_ENV,
+        diff(&expected, actual)
+    );
+    Ok(())
+}
+
+/// A line diff from `expected` to `actual`: `-` for lines only in the
+/// first, `+` for lines only in the second, and a few unchanged lines of
+/// context around each change, with line numbers in `expected`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old = expected.lines().collect::<Vec<_>>();
+    let new = actual.lines().collect::<Vec<_>>();
+
+    // Longest common subsequence of every pair of suffixes.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    // (sign, line in `expected`, text)
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', i, old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', i, old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', i, new[j]));
+            j += 1;
+        }
+    }
+    let has_lines = !old.is_empty() && !new.is_empty();
+    if has_lines && expected.ends_with('\n') != actual.ends_with('\n') {
+        lines.push(('!', old.len(), "(the final newline differs)"));
+    }
+
+    let changed = |k: usize| {
+        lines[k.saturating_sub(CONTEXT)..lines.len().min(k + CONTEXT + 1)]
+            .iter()
+            .any(|&(sign, _, _)| sign != ' ')
+    };
+    let mut out = String::new();
+    let mut skipped = false;
+    for (k, &(sign, line, text)) in lines.iter().enumerate() {
+        if !changed(k) {
+            skipped = true;
+            continue;
+        }
+        if skipped || k == 0 {
+            let _ = writeln!(out, "@@ line {} @@", line + 1);
+            skipped = false;
+        }
+        let _ = writeln!(out, "{}{}", sign, text);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let actual = "a\nb\nc\nD\ne\nf\ng\nh\ni\nj\nk\n";
+        assert_eq!(
+            diff(expected, actual),
+            "@@ line 2 @@\n b\n c\n-d\n+D\n e\n f\n@@ line 9 @@\n i\n j\n+k\n"
+        );
+        assert_eq!(
+            diff("x\n", "x"),
+            "@@ line 1 @@\n x\n!(the final newline differs)\n"
+        );
+        assert_eq!(diff("", "new\n"), "@@ line 1 @@\n+new\n");
+    }
+
+    #[test]
+    fn test_check() {
+        let path = std::env::temp_dir()
+            .join(format!("snapshot-{}", std::process::id()))
+            .join("grid.txt");
+        let error = check(&path, "#.\n.#\n", false).unwrap_err().to_string();
+        assert!(error.starts_with("no snapshot"));
+        check(&path, "#.\n.#\n", true).unwrap();
+        check(&path, "#.\n.#\n", false).unwrap();
+        let error = check(&path, "#.\n##\n", false).unwrap_err().to_string();
+        assert!(error.ends_with("@@ line 1 @@\n #.\n-.#\n+##\n"));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
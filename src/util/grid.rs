@@ -1,7 +1,10 @@
+use crate::util::parse::{ParseError, Span};
+use anyhow::{anyhow, Error};
 use itertools::Itertools;
 use std::fmt::Write;
 use std::fmt::{Debug, Display, Formatter};
 use std::io::BufRead;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone)]
 pub struct Grid<T> {
@@ -11,9 +14,281 @@ pub struct Grid<T> {
 }
 pub type Point = (usize, usize);
 
+/// A position or offset with signed components, for moves that may leave a
+/// grid on the way. Rows grow southwards, as in [`Point`].
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct Vec2 {
+    pub y: isize,
+    pub x: isize,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { y: 0, x: 0 };
+
+    pub const fn new(y: isize, x: isize) -> Self {
+        Self { y, x }
+    }
+
+    /// The Manhattan length, or the distance between two points as
+    /// `(a - b).manhattan()`.
+    pub fn manhattan(self) -> usize {
+        self.y.unsigned_abs() + self.x.unsigned_abs()
+    }
+
+    /// A quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.x, -self.y)
+    }
+
+    /// A quarter turn counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.x, self.y)
+    }
+
+    /// Both components wrapped into `0..bounds`, as on a torus.
+    pub fn rem_euclid(self, bounds: Vec2) -> Self {
+        Self::new(self.y.rem_euclid(bounds.y), self.x.rem_euclid(bounds.x))
+    }
+
+    /// The point, unless a component is negative.
+    pub fn to_point(self) -> Option<Point> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    /// The index of the cell in a row-major grid of `height` by `width`, or
+    /// `None` outside of it.
+    pub fn to_index(self, height: usize, width: usize) -> Option<usize> {
+        let (y, x) = self.to_point()?;
+        (y < height && x < width).then_some(y * width + x)
+    }
+
+    /// The cell at `index` in a row-major grid `width` cells wide.
+    pub fn from_index(index: usize, width: usize) -> Self {
+        Self::new((index / width) as isize, (index % width) as isize)
+    }
+}
+
+impl From<Point> for Vec2 {
+    fn from((y, x): Point) -> Self {
+        Self::new(y as isize, x as isize)
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        let (y, x) = direction.offset();
+        Self::new(y, x)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.y + rhs.y, self.x + rhs.x)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.y - rhs.y, self.x - rhs.x)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(self.y * rhs, self.x * rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.y, -self.x)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// A cell that can be read from one character of a puzzle input, for
+/// [`Grid::parse`] and [`Grid::from_reader`].
+///
+/// ```
+/// # use adv_code::util::grid::{FromCell, Grid};
+/// # use adv_code::util::parse::Span;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Tile {
+///     Wall,
+///     Floor,
+/// }
+///
+/// impl FromCell for Tile {
+///     fn from_cell(c: char) -> Option<Self> {
+///         match c {
+///             '#' => Some(Tile::Wall),
+///             '.' | 'S' => Some(Tile::Floor),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let (grid, [start]) = Grid::<Tile>::parse_marked(Span::new("##\n.S\n"), ['S'], Tile::from_cell).unwrap();
+/// assert_eq!((grid.get(&start), start), (Tile::Floor, (1, 1)));
+/// let error = Grid::<Tile>::parse(Span::new("##\n.?\n")).unwrap_err();
+/// assert_eq!(error.to_string(), "line 2, column 2: unexpected `?` in the grid");
+/// ```
+pub trait FromCell: Sized {
+    fn from_cell(c: char) -> Option<Self>;
+}
+
+impl FromCell for char {
+    fn from_cell(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl FromCell for u8 {
+    fn from_cell(c: char) -> Option<Self> {
+        c.is_ascii().then_some(c as u8)
+    }
+}
+
+/// What [`Grid::get`] and [`Grid::set`] take to name a cell.
+pub trait GridIndex {
+    /// The index of the cell in a row-major grid of `height` by `width`, or
+    /// `None` outside of it.
+    fn index(&self, height: usize, width: usize) -> Option<usize>;
+}
+
+impl GridIndex for Point {
+    fn index(&self, height: usize, width: usize) -> Option<usize> {
+        (self.0 < height && self.1 < width).then_some(self.0 * width + self.1)
+    }
+}
+
+impl GridIndex for Vec2 {
+    fn index(&self, height: usize, width: usize) -> Option<usize> {
+        self.to_index(height, width)
+    }
+}
+
+impl<I: GridIndex> GridIndex for &I {
+    fn index(&self, height: usize, width: usize) -> Option<usize> {
+        (*self).index(height, width)
+    }
+}
+
+/// A step to one of the eight cells around another, in clockwise order
+/// from north. The four orthogonal ones are [`Direction::ORTHOGONAL`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The (row, column) offset of a step, rows growing southwards.
+    pub fn offset(self) -> (isize, isize) {
+        use Direction::*;
+        match self {
+            North => (-1, 0),
+            NorthEast => (-1, 1),
+            East => (0, 1),
+            SouthEast => (1, 1),
+            South => (1, 0),
+            SouthWest => (1, -1),
+            West => (0, -1),
+            NorthWest => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The point one step away from `pt`, unless that is above or left of
+    /// the origin. See [`Grid::step`] to stay inside a grid as well.
+    pub fn step(self, pt: &Point) -> Option<Point> {
+        let (dy, dx) = self.offset();
+        Some((pt.0.checked_add_signed(dy)?, pt.1.checked_add_signed(dx)?))
+    }
+}
+
+/// Reads the arrows `^>v<` and the letters `URDL` used for moves in puzzle
+/// inputs.
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' => Ok(Direction::North),
+            '>' | 'R' => Ok(Direction::East),
+            'v' | 'D' => Ok(Direction::South),
+            '<' | 'L' => Ok(Direction::West),
+            _ => Err(anyhow!("not a direction: {:?}", c)),
+        }
+    }
+}
+
 impl<T> Grid<T>
 where
-    T: Default + Copy + PartialEq,
+    T: Copy + PartialEq,
 {
     pub fn new(v: Vec<T>, h: usize, w: usize) -> Self {
         Self {
@@ -22,43 +297,18 @@ where
             width: w,
         }
     }
-    pub fn from_reader<R: BufRead>(reader: R) -> Grid<u8> {
-        let mut w = 0;
-        let mut h = 0;
-        let mut v = Vec::new();
-        for s in reader.lines().map_while(Result::ok) {
-            if w == 0 {
-                w = s.len();
-            } else {
-                assert_eq!(w, s.len());
-            }
-            v.extend(s.bytes());
-            h += 1;
-        }
-        Grid::new(v, h, w)
-    }
-
-    pub fn from_reader_char<R: BufRead>(reader: R) -> Grid<char> {
-        let mut w = 0;
-        let mut h = 0;
-        let mut v = Vec::new();
-        for s in reader.lines().map_while(Result::ok) {
-            if w == 0 {
-                w = s.len();
-            } else {
-                assert_eq!(w, s.len());
-            }
-            v.extend(s.chars());
-            h += 1;
-        }
-        Grid::new(v, h, w)
+    /// Panics if `yx` is outside the grid; see [`Grid::try_get`].
+    pub fn get(&self, yx: &impl GridIndex) -> T {
+        self.array[self.index(yx)]
     }
-
-    pub fn get(&self, yx: &Point) -> T {
-        self.array[yx.0 * self.width + yx.1]
+    pub fn set(&mut self, yx: &impl GridIndex, val: T) {
+        let i = self.index(yx);
+        self.array[i] = val
     }
-    pub fn set(&mut self, yx: &Point, val: T) {
-        self.array[yx.0 * self.width + yx.1] = val
+
+    /// The cell at `yx`, or `None` outside the grid.
+    pub fn try_get(&self, yx: &impl GridIndex) -> Option<T> {
+        Some(self.array[yx.index(self.height, self.width)?])
     }
 
     pub fn map_index(&self, i: usize) -> Point {
@@ -72,6 +322,320 @@ where
     }
 }
 
+impl<T> Grid<T> {
+    /// One row per line of `text`, each character read as a cell by
+    /// `cell`. Rows of different lengths and characters `cell` has no cell
+    /// for are errors at their position.
+    pub fn parse_with(text: Span, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let (grid, []) = Self::parse_marked(text, [], cell)?;
+        Ok(grid)
+    }
+
+    /// As [`Grid::parse_with`], also finding where each of the `markers`
+    /// is, such as the start and end of a maze. Each must be in the grid
+    /// exactly once, and `cell` reads it like any other character.
+    pub fn parse_marked<const N: usize>(
+        text: Span,
+        markers: [char; N],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, [Point; N]), ParseError> {
+        let mut found = [None; N];
+        let mut array = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in text.lines() {
+            let error = |x: usize, message: String| ParseError {
+                line: line.line,
+                column: line.column + x,
+                message,
+            };
+            let mut x = 0;
+            for c in line.text.chars() {
+                if let Some(m) = markers.iter().position(|&m| m == c) {
+                    if found[m].is_some() {
+                        return Err(error(x, format!("a second `{}` in the grid", c)));
+                    }
+                    found[m] = Some((height, x));
+                }
+                array.push(
+                    cell(c).ok_or_else(|| error(x, format!("unexpected `{}` in the grid", c)))?,
+                );
+                x += 1;
+            }
+            let w = *width.get_or_insert(x);
+            if x != w {
+                return Err(error(
+                    x.min(w),
+                    format!("a row of {} cells, expected {}", x, w),
+                ));
+            }
+            height += 1;
+        }
+        let mut points = [(0, 0); N];
+        for (m, point) in points.iter_mut().enumerate() {
+            *point =
+                found[m].ok_or_else(|| text.error(format!("no `{}` in the grid", markers[m])))?;
+        }
+        let grid = Self {
+            array,
+            height,
+            width: width.unwrap_or(0),
+        };
+        Ok((grid, points))
+    }
+
+    fn index(&self, yx: &impl GridIndex) -> usize {
+        yx.index(self.height, self.width)
+            .unwrap_or_else(|| panic!("outside the {}x{} grid", self.height, self.width))
+    }
+
+    fn get_ref(&self, yx: &impl GridIndex) -> &T {
+        &self.array[self.index(yx)]
+    }
+
+    pub fn contains(&self, pt: &impl GridIndex) -> bool {
+        pt.index(self.height, self.width).is_some()
+    }
+
+    /// The cell one step from `pt` in `direction`, if it is in the grid.
+    pub fn step(&self, pt: &Point, direction: Direction) -> Option<Point> {
+        direction.step(pt).filter(|next| self.contains(next))
+    }
+
+    /// The orthogonal neighbors of `pt` inside the grid, clockwise from north.
+    pub fn neighbors4(&self, pt: &Point) -> impl Iterator<Item = Point> + '_ {
+        let pt = *pt;
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.step(&pt, d))
+    }
+
+    /// The neighbors of `pt` inside the grid, diagonals included, clockwise
+    /// from north.
+    pub fn neighbors8(&self, pt: &Point) -> impl Iterator<Item = Point> + '_ {
+        let pt = *pt;
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(&pt, d))
+    }
+
+    /// The grid as text, one line per row and one character per cell.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.array
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(&cell).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+/// Copies of the grid turned, mirrored or cut down, as with the views of
+/// [`Grid::view`].
+impl<T: Clone> Grid<T> {
+    /// Rows become columns: the cell at (y, x) moves to (x, y).
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self {
+        self.view().rotate_right().to_grid()
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        self.view().rotate_left().to_grid()
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        self.view().rotate_180().to_grid()
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.view().flip_vertical().to_grid()
+    }
+
+    /// The `height` by `width` cells from `top_left` on.
+    pub fn sub_grid(&self, top_left: Point, height: usize, width: usize) -> Self {
+        self.view().sub_grid(top_left, height, width).to_grid()
+    }
+}
+
+impl<T> Grid<T> {
+    /// The whole grid as a view, to turn, mirror or cut down without
+    /// copying any cell.
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            origin: Vec2::ZERO,
+            row_step: Vec2::new(1, 0),
+            col_step: Vec2::new(0, 1),
+            height: self.height,
+            width: self.width,
+        }
+    }
+}
+
+/// A rectangle of a borrowed [`Grid`], maybe turned or mirrored. Each
+/// transformation only changes where the view starts and which way its rows
+/// and columns run in the grid, so they all take constant time.
+///
+/// ```
+/// # use adv_code::util::grid::Grid;
+/// let grid = Grid::<char>::from_reader("abc\ndef\n".as_bytes()).unwrap();
+/// let view = grid.view().rotate_right();
+/// assert_eq!((view.height(), view.width()), (3, 2));
+/// assert_eq!(view.to_grid().render(|&c| c), "da\neb\nfc\n");
+/// assert_eq!(view.sub_grid((1, 0), 2, 2).iter().collect::<String>(), "ebfc");
+/// ```
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    /// Where the view's (0, 0) is in the grid.
+    origin: Vec2,
+    /// How far one row down the view moves in the grid.
+    row_step: Vec2,
+    /// How far one column right moves.
+    col_step: Vec2,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The cell at `yx` in the view; panics outside of it.
+    pub fn get(&self, yx: &impl GridIndex) -> &'a T {
+        self.try_get(yx)
+            .unwrap_or_else(|| panic!("outside the {}x{} view", self.height, self.width))
+    }
+
+    pub fn try_get(&self, yx: &impl GridIndex) -> Option<&'a T> {
+        let i = yx.index(self.height, self.width)?;
+        let (y, x) = (i / self.width, i % self.width);
+        let cell = self.origin + self.row_step * y as isize + self.col_step * x as isize;
+        Some(self.grid.get_ref(&cell))
+    }
+
+    /// The cells row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.height)
+            .cartesian_product(0..self.width)
+            .map(|yx| self.get(&yx))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            array: self.iter().cloned().collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            row_step: self.col_step,
+            col_step: self.row_step,
+            height: self.width,
+            width: self.height,
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        Self {
+            origin: self.origin + self.col_step * (self.width as isize - 1),
+            col_step: -self.col_step,
+            ..self
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        Self {
+            origin: self.origin + self.row_step * (self.height as isize - 1),
+            row_step: -self.row_step,
+            ..self
+        }
+    }
+
+    pub fn rotate_right(self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_left(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    pub fn rotate_180(self) -> Self {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// The `height` by `width` cells from `top_left` on, in the view's own
+    /// coordinates; panics if they are not all in the view.
+    pub fn sub_grid(self, top_left: Point, height: usize, width: usize) -> Self {
+        assert!(
+            top_left.0 + height <= self.height && top_left.1 + width <= self.width,
+            "{}x{} from {:?} is outside the {}x{} view",
+            height,
+            width,
+            top_left,
+            self.height,
+            self.width
+        );
+        Self {
+            origin: self.origin
+                + self.row_step * top_left.0 as isize
+                + self.col_step * top_left.1 as isize,
+            height,
+            width,
+            ..self
+        }
+    }
+}
+
+/// Views are equal when they have the same size and cells, wherever they
+/// come from.
+impl<T: PartialEq> PartialEq for GridView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.height, self.width) == (other.height, other.width) && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: FromCell> Grid<T> {
+    /// One row per line of `text` and one cell per character; see
+    /// [`Grid::parse_with`].
+    pub fn parse(text: Span) -> Result<Self, ParseError> {
+        Self::parse_with(text, T::from_cell)
+    }
+
+    pub fn from_reader<R: BufRead>(mut reader: R) -> anyhow::Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(Self::parse(Span::new(&input))?)
+    }
+}
+
 impl<T> Display for Grid<T>
 where
     T: Debug,
@@ -88,3 +652,142 @@ where
         write!(f, "{}", s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        use Direction::*;
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(NorthEast.turn_right(), SouthEast);
+        assert_eq!(SouthWest.opposite(), NorthEast);
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.turn_left().turn_right() == *d && d.opposite().opposite() == *d));
+        assert_eq!(
+            "^>v<"
+                .chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            Direction::ORTHOGONAL
+        );
+        assert!(Direction::try_from('x').is_err());
+        assert_eq!(North.step(&(0, 3)), None);
+        assert_eq!(SouthWest.step(&(2, 3)), Some((3, 2)));
+    }
+
+    #[test]
+    fn test_vec2() {
+        let (a, b) = (Vec2::new(1, -2), Vec2::new(-3, 4));
+        assert_eq!(a + b, Vec2::new(-2, 2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(-a * 3, Vec2::new(-3, 6));
+        assert_eq!((a - b).manhattan(), 10);
+        assert_eq!(
+            Vec2::from(Direction::North).rotate_right(),
+            Direction::East.into()
+        );
+        assert_eq!(a.rotate_left().rotate_right(), a);
+        assert_eq!(
+            Vec2::new(-1, 12).rem_euclid(Vec2::new(7, 11)),
+            Vec2::new(6, 1)
+        );
+
+        assert_eq!(Vec2::new(1, 2).to_index(2, 3), Some(5));
+        assert_eq!(Vec2::new(1, 3).to_index(2, 3), None);
+        assert_eq!(Vec2::new(-1, 0).to_point(), None);
+        assert_eq!(Vec2::from_index(5, 3), Vec2::new(1, 2));
+
+        let mut grid = Grid::new(vec![0u8; 6], 2, 3);
+        grid.set(&Vec2::new(1, 2), 7);
+        assert_eq!(grid.get(&(1, 2)), 7);
+        assert_eq!(grid.try_get(&Vec2::new(1, 2)), Some(7));
+        assert_eq!(grid.try_get(&Vec2::new(0, -1)), None);
+        assert_eq!(grid.try_get(&(2, 0)), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::<u8>::from_reader("#.#\n.@.\n".as_bytes()).unwrap();
+        assert_eq!((grid.height, grid.width), (2, 3));
+        assert_eq!(grid.get(&(1, 1)), b'@');
+        assert_eq!(
+            Grid::<u8>::parse(Span::new("#.\n.é\n"))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: unexpected `é` in the grid"
+        );
+
+        let error = |text| {
+            Grid::<char>::parse_marked(Span::new(text), ['S', 'E'], Some)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("S..\n.E\n"),
+            "line 2, column 3: a row of 2 cells, expected 3"
+        );
+        assert_eq!(
+            error("S..\n.E..\n"),
+            "line 2, column 4: a row of 4 cells, expected 3"
+        );
+        assert_eq!(
+            error("S.S\n.E.\n"),
+            "line 1, column 3: a second `S` in the grid"
+        );
+        assert_eq!(error("S..\n...\n"), "line 1, column 1: no `E` in the grid");
+        let (grid, [start, end]) =
+            Grid::<char>::parse_marked(Span::new("S..\n.E.\n"), ['S', 'E'], Some).unwrap();
+        assert_eq!((start, end, grid.get(&end)), ((0, 0), (1, 1), 'E'));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::<char>::from_reader("abc\ndef\n".as_bytes()).unwrap();
+        let text = |grid: Grid<char>| grid.render(|&c| c);
+        assert_eq!(text(grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(text(grid.rotate_right()), "da\neb\nfc\n");
+        assert_eq!(text(grid.rotate_left()), "cf\nbe\nad\n");
+        assert_eq!(text(grid.rotate_180()), "fed\ncba\n");
+        assert_eq!(text(grid.flip_horizontal()), "cba\nfed\n");
+        assert_eq!(text(grid.flip_vertical()), "def\nabc\n");
+        assert_eq!(text(grid.sub_grid((0, 1), 2, 2)), "bc\nef\n");
+
+        let view = grid.view();
+        assert!(
+            view.rotate_right()
+                .rotate_right()
+                .rotate_right()
+                .rotate_right()
+                == view
+        );
+        assert!(view.rotate_left().rotate_left() == view.rotate_180());
+        assert!(view.flip_horizontal() != view);
+        let turned = view.rotate_right().sub_grid((1, 0), 2, 2);
+        assert_eq!(turned.iter().collect::<String>(), "ebfc");
+        assert_eq!(*turned.get(&Vec2::new(1, 1)), 'c');
+        assert_eq!(turned.try_get(&(2, 0)), None);
+
+        let symmetric = Grid::<char>::from_reader(".#.\n###\n".as_bytes()).unwrap();
+        assert!(symmetric.view() == symmetric.view().flip_horizontal());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(vec![0u8; 6], 2, 3);
+        assert_eq!(grid.neighbors4(&(0, 0)).collect_vec(), [(0, 1), (1, 0)]);
+        assert_eq!(
+            grid.neighbors4(&(1, 1)).collect_vec(),
+            [(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8(&(0, 1)).collect_vec(),
+            [(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+        assert_eq!(grid.step(&(1, 2), Direction::East), None);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::util::num::Int;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+/// A set of integers stored as sorted, disjoint half-open ranges. Touching
+/// or overlapping ranges are merged as they go in.
+///
+/// Besides the ranges by start, it indexes them by length, so looking for
+/// the first range long enough for something, as when allocating disk space
+/// or free seats, only visits the lengths that would fit rather than every
+/// range:
+///
+/// ```
+/// # use adv_code::util::intervals::IntervalSet;
+/// let mut free = IntervalSet::from_iter([2..5, 8..9, 12..15]);
+/// assert_eq!(free.first_gap(3, 40), Some(2));
+/// free.remove(2..5);
+/// assert_eq!(free.first_gap(3, 40), Some(12));
+/// assert_eq!(free.first_gap(3, 12), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// End of each range by its start.
+    ranges: BTreeMap<T, T>,
+    /// Starts of the ranges by their length.
+    by_len: BTreeMap<T, BTreeSet<T>>,
+}
+
+impl<T: Int> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+            by_len: BTreeMap::new(),
+        }
+    }
+
+    /// The number of separate ranges.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many integers the set holds.
+    pub fn measure(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |total, (&start, &end)| total + (end - start))
+    }
+
+    /// The ranges in order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..end)
+    }
+
+    /// The range holding `x`, if any.
+    pub fn range_of(&self, x: T) -> Option<Range<T>> {
+        let (&start, &end) = self.ranges.range(..=x).next_back()?;
+        (x < end).then_some(start..end)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.range_of(x).is_some()
+    }
+
+    fn add(&mut self, start: T, end: T) {
+        self.ranges.insert(start, end);
+        self.by_len.entry(end - start).or_default().insert(start);
+    }
+
+    fn delete(&mut self, start: T) -> T {
+        let end = self
+            .ranges
+            .remove(&start)
+            .expect("interval set lost a range");
+        let len = end - start;
+        let starts = self
+            .by_len
+            .get_mut(&len)
+            .expect("interval set lost a length");
+        starts.remove(&start);
+        if starts.is_empty() {
+            self.by_len.remove(&len);
+        }
+        end
+    }
+
+    /// Adds every integer in `range`, merging it with the ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        let (mut start, mut end) = (range.start, range.end);
+        if start >= end {
+            return;
+        }
+        if let Some((&s, &e)) = self.ranges.range(..start).next_back() {
+            if e >= start {
+                self.delete(s);
+                start = s;
+                end = end.max(e);
+            }
+        }
+        while let Some((&s, _)) = self.ranges.range(start..=end).next() {
+            end = end.max(self.delete(s));
+        }
+        self.add(start, end);
+    }
+
+    /// Takes out every integer in `range`, splitting the ranges it cuts.
+    pub fn remove(&mut self, range: Range<T>) {
+        let (start, end) = (range.start, range.end);
+        if start >= end {
+            return;
+        }
+        if let Some((&s, &e)) = self.ranges.range(..start).next_back() {
+            if e > start {
+                self.delete(s);
+                self.add(s, start);
+                if e > end {
+                    self.add(end, e);
+                }
+            }
+        }
+        while let Some((&s, _)) = self.ranges.range(start..end).next() {
+            let e = self.delete(s);
+            if e > end {
+                self.add(end, e);
+            }
+        }
+    }
+
+    /// Where the first range at least `len` long starts, among the ranges
+    /// starting before `before`. Takes time in the number of distinct range
+    /// lengths of at least `len`, not the number of ranges.
+    pub fn first_gap(&self, len: T, before: T) -> Option<T> {
+        self.by_len
+            .range(len..)
+            .filter_map(|(_, starts)| starts.first().copied())
+            .min()
+            .filter(|&start| start < before)
+    }
+}
+
+impl<T: Int> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Int> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::from_iter([5..8, 1..3, 10..12]);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..3, 5..8, 10..12]);
+        set.insert(3..5);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..8, 10..12]);
+        set.insert(7..11);
+        set.insert(4..4);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..12]);
+        set.insert(-5i64..-2);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.measure(), 14);
+        assert!(set.contains(-3) && !set.contains(-2) && set.contains(11));
+        assert_eq!(set.range_of(6), Some(1..12));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = IntervalSet::from_iter([0u32..10, 12..20, 25..30]);
+        set.remove(3..5);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![0..3, 5..10, 12..20, 25..30]
+        );
+        set.remove(8..27);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..3, 5..8, 27..30]);
+        set.remove(0..100);
+        assert!(set.is_empty());
+        assert_eq!(set, IntervalSet::new());
+    }
+
+    #[test]
+    fn test_first_gap() {
+        let mut set = IntervalSet::from_iter([2usize..4, 6..7, 9..12, 15..18]);
+        assert_eq!(set.first_gap(1, 100), Some(2));
+        assert_eq!(set.first_gap(3, 100), Some(9));
+        assert_eq!(set.first_gap(3, 9), None);
+        assert_eq!(set.first_gap(4, 100), None);
+        set.remove(9..10);
+        assert_eq!(set.first_gap(3, 100), Some(15));
+        set.insert(12..15);
+        assert_eq!(set.first_gap(9, 100), None);
+        assert_eq!(set.first_gap(8, 11), Some(10));
+    }
+}
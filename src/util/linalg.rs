@@ -0,0 +1,337 @@
+use crate::util::num::gcd;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+/// Arithmetic panics rather than wrapping if an `i128` overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// `num / den`; panics if `den` is 0.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert_ne!(den, 0, "zero denominator");
+        let g = gcd(num, den) * den.signum();
+        Self {
+            num: num / g,
+            den: den / g,
+        }
+    }
+
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.num)
+    }
+
+    pub fn recip(&self) -> Self {
+        Self::new(self.den, self.num)
+    }
+}
+
+fn mul(a: i128, b: i128) -> i128 {
+    a.checked_mul(b).expect("rational overflow")
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        let g = gcd(self.den, rhs.den);
+        let num = mul(self.num, rhs.den / g)
+            .checked_add(mul(rhs.num, self.den / g))
+            .expect("rational overflow");
+        Rational::new(num, mul(self.den / g, rhs.den))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        // Cancel crosswise first to keep the products small.
+        let a = gcd(self.num, rhs.den);
+        let b = gcd(rhs.num, self.den);
+        Rational::new(
+            mul(self.num / a, rhs.num / b),
+            mul(self.den / b, rhs.den / a),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Rational {
+        assert!(!rhs.is_zero(), "division by zero");
+        let a = gcd(self.num, rhs.num);
+        let b = gcd(self.den, rhs.den);
+        Rational::new(
+            mul(self.num / a, rhs.den / b),
+            mul(self.den / b, rhs.num / a),
+        )
+    }
+}
+
+/// Why a system has no single solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinalgError {
+    /// The rows differ in length from each other or from the right-hand
+    /// side, or the matrix is not square where it has to be.
+    Shape(String),
+    /// The equations contradict each other, so nothing solves them.
+    Inconsistent,
+    /// Only `rank` of the equations are independent, fewer than the number
+    /// of unknowns, so there are infinitely many solutions.
+    Underdetermined { rank: usize, unknowns: usize },
+}
+
+impl Display for LinalgError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinalgError::Shape(message) => write!(f, "bad shape: {}", message),
+            LinalgError::Inconsistent => write!(f, "singular system with no solution"),
+            LinalgError::Underdetermined { rank, unknowns } => write!(
+                f,
+                "underdetermined system: rank {} for {} unknowns",
+                rank, unknowns
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LinalgError {}
+
+fn width(a: &[Vec<i128>]) -> Result<usize, LinalgError> {
+    let width = a.first().map_or(0, Vec::len);
+    if a.iter().any(|row| row.len() != width) {
+        return Err(LinalgError::Shape(String::from(
+            "rows of different lengths",
+        )));
+    }
+    Ok(width)
+}
+
+/// The determinant of a square matrix, by fraction-free (Bareiss)
+/// elimination so every intermediate value stays an integer.
+pub fn determinant(a: &[Vec<i128>]) -> Result<i128, LinalgError> {
+    let n = a.len();
+    if width(a)? != n && n > 0 {
+        return Err(LinalgError::Shape(format!(
+            "{}x{} matrix is not square",
+            n,
+            a[0].len()
+        )));
+    }
+    if n == 0 {
+        return Ok(1);
+    }
+    let mut m = a.to_vec();
+    let (mut sign, mut previous) = (1, 1);
+    for k in 0..n - 1 {
+        if m[k][k] == 0 {
+            let Some(swap) = (k + 1..n).find(|&i| m[i][k] != 0) else {
+                return Ok(0);
+            };
+            m.swap(k, swap);
+            sign = -sign;
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                m[i][j] = (mul(m[i][j], m[k][k]) - mul(m[i][k], m[k][j])) / previous;
+            }
+        }
+        previous = m[k][k];
+    }
+    Ok(sign * m[n - 1][n - 1])
+}
+
+/// The single exact solution of `a x = b` by Gauss-Jordan elimination.
+/// Extra equations are fine as long as they agree with the rest.
+///
+/// ```
+/// # use adv_code::util::linalg::{solve, LinalgError, Rational};
+/// let x = solve(&[vec![2, 1], vec![1, 3]], &[3, 5]).unwrap();
+/// assert_eq!(x, [Rational::new(4, 5), Rational::new(7, 5)]);
+/// assert_eq!(solve(&[vec![1, 2], vec![2, 4]], &[3, 7]), Err(LinalgError::Inconsistent));
+/// ```
+pub fn solve(a: &[Vec<i128>], b: &[i128]) -> Result<Vec<Rational>, LinalgError> {
+    let unknowns = width(a)?;
+    if a.len() != b.len() {
+        return Err(LinalgError::Shape(format!(
+            "{} equations but {} right-hand sides",
+            a.len(),
+            b.len()
+        )));
+    }
+    let mut m = a
+        .iter()
+        .zip(b)
+        .map(|(row, &rhs)| {
+            row.iter()
+                .chain([&rhs])
+                .map(|&v| Rational::from(v))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut pivots = Vec::new();
+    for col in 0..unknowns {
+        let rank = pivots.len();
+        let Some(row) = (rank..m.len()).find(|&r| !m[r][col].is_zero()) else {
+            continue;
+        };
+        m.swap(rank, row);
+        let pivot = m[rank][col];
+        for v in &mut m[rank] {
+            *v = *v / pivot;
+        }
+        let pivot_row = m[rank].clone();
+        for (r, row) in m.iter_mut().enumerate() {
+            let factor = row[col];
+            if r == rank || factor.is_zero() {
+                continue;
+            }
+            for (v, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *v = *v - factor * p;
+            }
+        }
+        pivots.push(col);
+    }
+
+    let rank = pivots.len();
+    if m[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Err(LinalgError::Inconsistent);
+    }
+    if rank < unknowns {
+        return Err(LinalgError::Underdetermined { rank, unknowns });
+    }
+    Ok(m[..rank].iter().map(|row| row[unknowns]).collect())
+}
+
+/// The solution of `a x = b` if it is unique and all integers, `None` if it
+/// is unique but fractional.
+pub fn solve_integer(a: &[Vec<i128>], b: &[i128]) -> Result<Option<Vec<i128>>, LinalgError> {
+    Ok(solve(a, b)?.iter().map(Rational::to_integer).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.num(), half.den()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half - Rational::ONE, Rational::new(-1, 2));
+        assert_eq!(half * Rational::from(4), Rational::from(2));
+        assert_eq!((half / Rational::new(-1, 4)).to_integer(), Some(-2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+        assert_eq!(Rational::new(-7, 3).to_string(), "-7/3");
+        let big = Rational::new(i128::MAX, 3);
+        assert_eq!(big * Rational::new(3, i128::MAX), Rational::ONE);
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(determinant(&[vec![94, 22], vec![34, 67]]), Ok(5550));
+        assert_eq!(
+            determinant(&[vec![0, 2, 1], vec![1, 0, 3], vec![4, 5, 0]]),
+            Ok(29)
+        );
+        assert_eq!(determinant(&[vec![1, 2], vec![2, 4]]), Ok(0));
+        assert_eq!(determinant(&[]), Ok(1));
+        assert!(matches!(
+            determinant(&[vec![1, 2, 3], vec![4, 5, 6]]),
+            Err(LinalgError::Shape(_))
+        ));
+    }
+
+    #[test]
+    fn test_solve() {
+        let prize = 10_000_000_000_000;
+        assert_eq!(
+            solve_integer(
+                &[vec![26, 67], vec![66, 21]],
+                &[prize + 12748, prize + 12176]
+            ),
+            Ok(Some(vec![118679050709, 103199174542]))
+        );
+        assert_eq!(
+            solve_integer(&[vec![94, 22], vec![34, 67]], &[prize + 8400, prize + 5400]),
+            Ok(None)
+        );
+        assert_eq!(
+            solve(&[vec![1, 1], vec![2, 2], vec![1, -1]], &[4, 8, 0]),
+            Ok(vec![Rational::from(2), Rational::from(2)])
+        );
+        assert_eq!(
+            solve(&[vec![1, 2], vec![2, 4]], &[3, 6]),
+            Err(LinalgError::Underdetermined {
+                rank: 1,
+                unknowns: 2
+            })
+        );
+        assert_eq!(
+            solve(&[vec![1, 2]], &[3, 4]).unwrap_err().to_string(),
+            "bad shape: 1 equations but 2 right-hand sides"
+        );
+    }
+}
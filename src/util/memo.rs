@@ -0,0 +1,246 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// How well a [`Memo`] did.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl Stats {
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evictions",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.,
+            self.evictions
+        )
+    }
+}
+
+/// A cache of computed values for recursive counting solvers.
+///
+/// Lookups take borrowed keys, so a `Memo<String, _>` is queried with a
+/// `&str` and only allocates a key when it stores a new value:
+///
+/// ```
+/// # use adv_code::util::memo::Memo;
+/// let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+/// let mut memo = Memo::<String, usize>::new();
+/// let ways = memo.recurse("brwrr", &|ways, design: &str| {
+///     if design.is_empty() {
+///         return 1;
+///     }
+///     patterns
+///         .iter()
+///         .filter_map(|p| design.strip_prefix(p))
+///         .map(&mut *ways)
+///         .sum()
+/// });
+/// assert_eq!(ways, 2);
+/// ```
+///
+/// A [`Memo::bounded`] one keeps at most that many values, dropping the
+/// least recently used first.
+pub struct Memo<K, V> {
+    values: HashMap<K, (V, u64)>,
+    /// Keys by last use, only kept when bounded.
+    order: BTreeMap<u64, K>,
+    capacity: Option<usize>,
+    clock: u64,
+    stats: Stats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            order: BTreeMap::new(),
+            capacity: None,
+            clock: 0,
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A memo holding at most `capacity` values.
+    pub fn bounded(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity.max(1)),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// The value stored for `key`, counted as a hit or a miss.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some((value, used)) = self.values.get_mut(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        self.stats.hits += 1;
+        if self.capacity.is_some() {
+            self.clock += 1;
+            let key = self.order.remove(used).expect("bounded memo lost a key");
+            self.order.insert(self.clock, key);
+            *used = self.clock;
+        }
+        Some(value.clone())
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.clock += 1;
+        if let Some(capacity) = self.capacity {
+            if let Some((_, used)) = self.values.get(&key) {
+                self.order.remove(used);
+            }
+            self.order.insert(self.clock, key.clone());
+            if !self.values.contains_key(&key) && self.values.len() == capacity {
+                if let Some((_, oldest)) = self.order.pop_first() {
+                    self.values.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+            }
+        }
+        self.values.insert(key, (value, self.clock));
+    }
+
+    /// The value for `key`, computed by `compute` and stored on a miss.
+    /// `compute` gets the memo back so it can recurse through a named
+    /// function:
+    ///
+    /// ```
+    /// # use adv_code::util::memo::Memo;
+    /// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+    ///     if n < 2 {
+    ///         return n;
+    ///     }
+    ///     memo.get_or_compute(&n, |memo| fib(memo, n - 1) + fib(memo, n - 2))
+    /// }
+    /// assert_eq!(fib(&mut Memo::new(), 90), 2880067194370816120);
+    /// ```
+    pub fn get_or_compute<Q, F>(&mut self, key: &Q, compute: F) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.get(key) {
+            return value;
+        }
+        let value = compute(self);
+        self.insert(key.to_owned(), value.clone());
+        value
+    }
+
+    /// Memoizes a recursive closure: `f` is called with a function for its
+    /// recursive calls and the key to compute.
+    pub fn recurse<Q, F>(&mut self, key: &Q, f: &F) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: Fn(&mut dyn FnMut(&Q) -> V, &Q) -> V,
+    {
+        if let Some(value) = self.get(key) {
+            return value;
+        }
+        let value = f(&mut |key: &Q| self.recurse(key, f), key);
+        self.insert(key.to_owned(), value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurse() {
+        let mut memo = Memo::<u64, u64>::new();
+        let fib = |fib: &mut dyn FnMut(&u64) -> u64, &n: &u64| {
+            if n < 2 {
+                n
+            } else {
+                fib(&(n - 1)) + fib(&(n - 2))
+            }
+        };
+        assert_eq!(memo.recurse(&50, &fib), 12586269025);
+        assert_eq!(memo.len(), 51);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 48,
+                misses: 51,
+                evictions: 0
+            }
+        );
+        assert_eq!(memo.recurse(&50, &fib), 12586269025);
+        assert_eq!(memo.stats().hits, 49);
+    }
+
+    #[test]
+    fn test_borrowed_keys() {
+        let mut memo = Memo::<String, usize>::new();
+        assert_eq!(memo.get("abc"), None);
+        assert_eq!(memo.get_or_compute("abc", |_| 3), 3);
+        assert_eq!(memo.get_or_compute("abc", |_| unreachable!()), 3);
+        assert_eq!(memo.get("abc"), Some(3));
+        assert_eq!(
+            memo.stats().to_string(),
+            "2 hits, 2 misses (50.0% hit rate), 0 evictions"
+        );
+    }
+
+    #[test]
+    fn test_bounded() {
+        let mut memo = Memo::bounded(2);
+        memo.insert(1, 'a');
+        memo.insert(2, 'b');
+        assert_eq!(memo.get(&1), Some('a'));
+        memo.insert(3, 'c');
+        assert_eq!(memo.get(&2), None);
+        assert_eq!((memo.get(&1), memo.get(&3)), (Some('a'), Some('c')));
+        memo.insert(3, 'C');
+        memo.insert(4, 'd');
+        assert_eq!(memo.len(), 2);
+        assert_eq!((memo.get(&1), memo.get(&3)), (None, Some('C')));
+        assert_eq!(memo.stats().evictions, 2);
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The primitive integers, so the functions here work at any width.
+pub trait Int:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn abs(self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+/// The signed integers, needed wherever Bézout coefficients go negative.
+pub trait Signed: Int + Neg<Output = Self> {}
+
+macro_rules! impl_int {
+    ($abs:ident; $($t:ty),*) => {$(
+        impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TEN: Self = 10;
+
+            fn abs(self) -> Self {
+                impl_int!(@$abs self)
+            }
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                <$t>::checked_pow(self, exp)
+            }
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        }
+    )*};
+    (@signed $x:ident) => { $x.abs() };
+    (@unsigned $x:ident) => { $x };
+}
+
+impl_int!(unsigned; u8, u16, u32, u64, u128, usize);
+impl_int!(signed; i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// The greatest common divisor, never negative; `gcd(0, 0)` is 0.
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative; `None` if it overflows.
+pub fn lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a.abs() / gcd(a, b)).checked_mul(b.abs())
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn ext_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    let m = m.abs();
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// `a * b mod m` in `0..m`, without overflowing even when `a * b` would.
+pub fn mul_mod<T: Int>(a: T, b: T, m: T) -> T {
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    let two = T::ONE + T::ONE;
+    let mut result = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+    result
+}
+
+/// `a + b mod m` for `a` and `b` already in `0..m`.
+fn add_mod<T: Int>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base ^ exp mod m` in `0..m`, by repeated squaring.
+pub fn pow_mod<T: Int>(base: T, mut exp: u64, m: T) -> T {
+    let mut base = base.rem_euclid(m);
+    let mut result = T::ONE % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Chinese remainder theorem: the `x` in `0..lcm` with `x ≡ r (mod m)` for
+/// every `(r, m)`, along with that lcm of the moduli. The moduli need not be
+/// coprime; `None` if the congruences conflict or the lcm overflows.
+///
+/// ```
+/// # use adv_code::util::num::crt;
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt<T: Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let (mut x, mut m) = (T::ZERO, T::ONE);
+    for &(r, n) in congruences {
+        let n = n.abs();
+        let (g, p, _) = ext_gcd(m, n);
+        let diff = (r - x).rem_euclid(n);
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let step = n / g;
+        let t = mul_mod(diff / g, p, step);
+        let lcm = m.checked_mul(step)?;
+        x = (x + m * t).rem_euclid(lcm);
+        m = lcm;
+    }
+    Some((x, m))
+}
+
+/// `10 ^ exp`, or `None` if it overflows.
+pub fn pow10<T: Int>(exp: u32) -> Option<T> {
+    T::TEN.checked_pow(exp)
+}
+
+/// The number of decimal digits, ignoring the sign; 0 has one.
+pub fn digit_count<T: Int>(n: T) -> u32 {
+    let mut n = n;
+    let mut count = 1;
+    while n / T::TEN != T::ZERO {
+        n = n / T::TEN;
+        count += 1;
+    }
+    count
+}
+
+/// Splits off the last `low` digits: `split_digits(253000, 3)` is
+/// `(253, 0)`. `None` if `10 ^ low` overflows.
+pub fn split_digits<T: Int>(n: T, low: u32) -> Option<(T, T)> {
+    let divisor = pow10(low)?;
+    Some((n / divisor, n % divisor))
+}
+
+/// The digits of `a` followed by those of `b`, `concat(12, 345)` being
+/// 12345; `None` if that overflows.
+pub fn concat<T: Int>(a: T, b: T) -> Option<T> {
+    a.checked_mul(pow10(digit_count(b))?)?.checked_add(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u8, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0u64, 0), 0);
+        assert_eq!(lcm(4i128, -6), Some(12));
+        assert_eq!(lcm(0usize, 7), Some(0));
+        assert_eq!(lcm(200u8, 3), None);
+
+        let (g, x, y) = ext_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        let (g, x, y) = ext_gcd(-9i8, 6);
+        assert_eq!((g, -9 * x + 6 * y), (3, 3));
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_inverse(3i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(6i32, 9), None);
+        assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 2, u64::MAX), 2);
+        assert_eq!(pow_mod(2u32, 10, 1000), 24);
+        assert_eq!(pow_mod(3i64, 0, 1), 0);
+        assert_eq!(pow_mod(-2i128, 3, 5), 2);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3i32, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1i8, 5)]), Some((4, 5)));
+        assert_eq!(
+            crt(&[(1i128, 1_000_000_007), (2, 998_244_353), (3, 1_000_000_009)])
+                .map(|(x, _)| { (x % 1_000_000_007, x % 998_244_353, x % 1_000_000_009) }),
+            Some((1, 2, 3))
+        );
+        assert_eq!(crt(&[(0i8, 16), (0, 15)]), None);
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digit_count(0u8), 1);
+        assert_eq!(digit_count(-4096i32), 4);
+        assert_eq!(digit_count(u128::MAX), 39);
+        assert_eq!(split_digits(253000u64, 3), Some((253, 0)));
+        assert_eq!(split_digits(1234u16, 5), None);
+        assert_eq!(concat(12usize, 345), Some(12345));
+        assert_eq!(concat(6u8, 0), Some(60));
+        assert_eq!(concat(25u8, 6), None);
+        assert_eq!(concat(i64::MAX / 10, 7), Some(i64::MAX));
+        assert_eq!(pow10::<i128>(38), Some(10i128.pow(38)));
+    }
+}
@@ -0,0 +1,108 @@
+//! Data parallelism for days whose items are independent, such as one
+//! report or equation per line.
+//!
+//! With the `parallel` feature the items are split into one contiguous
+//! chunk per thread of a rayon pool; without it, or inside [`serial`],
+//! everything runs on the calling thread. Either way the results come back
+//! in the order of the items, so reducing them afterwards gives the same
+//! answer however many threads there were.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SERIAL: AtomicBool = AtomicBool::new(false);
+
+/// Runs `f` with every map in it on the calling thread, to time it against
+/// the parallel run.
+pub fn serial<T>(f: impl FnOnce() -> T) -> T {
+    let was = SERIAL.swap(true, Ordering::SeqCst);
+    let result = f();
+    SERIAL.store(was, Ordering::SeqCst);
+    result
+}
+
+/// How many threads a map may use right now.
+pub fn threads() -> usize {
+    if SERIAL.load(Ordering::SeqCst) {
+        return 1;
+    }
+    #[cfg(feature = "parallel")]
+    return rayon::current_num_threads();
+    #[cfg(not(feature = "parallel"))]
+    1
+}
+
+/// `f` of every item, in order.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    map_with(items, || (), |_, item| f(item))
+}
+
+/// `f` of every item, in order, with some state such as a cache that each
+/// chunk of the items starts afresh from `init`.
+pub fn map_with<T, S, R, I, F>(items: &[T], init: I, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    I: Fn() -> S + Sync,
+    F: Fn(&mut S, &T) -> R + Sync,
+{
+    let chunk = |chunk: &[T]| {
+        let mut state = init();
+        chunk
+            .iter()
+            .map(|item| f(&mut state, item))
+            .collect::<Vec<_>>()
+    };
+    let threads = threads();
+    if threads <= 1 || items.len() <= 1 {
+        return chunk(items);
+    }
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        let size = items.len().div_ceil(threads);
+        items
+            .par_chunks(size)
+            .map(chunk)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    chunk(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items = (0..1000u64).collect::<Vec<_>>();
+        let squares = map(&items, |&x| x * x);
+        assert_eq!(squares, items.iter().map(|x| x * x).collect::<Vec<_>>());
+        assert_eq!(serial(|| map(&items, |&x| x * x)), squares);
+        assert_eq!(serial(threads), 1);
+        assert!(map(&[] as &[u8], |&x| x).is_empty());
+
+        // Each chunk counts from 0 again, but in order.
+        let count = |seen: &mut usize, _: &u64| {
+            *seen += 1;
+            *seen
+        };
+        let counts = map_with(&items, || 0, count);
+        assert_eq!(counts.len(), 1000);
+        assert_eq!(counts[0], 1);
+        let chunks = match threads() {
+            1 => 1,
+            threads => 1000usize.div_ceil(1000usize.div_ceil(threads)),
+        };
+        assert_eq!(counts.iter().filter(|&&c| c == 1).count(), chunks);
+        assert_eq!(serial(|| map_with(&items, || 0, count))[999], 1000);
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// What went wrong, and where: lines and columns count from 1, columns in
+/// characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of the input that remembers where it starts, so anything parsed
+/// out of it reports the position of the problem.
+///
+/// ```
+/// # use adv_code::util::parse;
+/// let (gate, output) = parse::lines("x00 AND y00 -> z00").next().unwrap().arrow().unwrap();
+/// assert_eq!((gate.text, output.text), ("x00 AND y00", "z00"));
+/// let error = output.parse::<u8>().unwrap_err();
+/// assert_eq!(error.to_string(), "line 1, column 16: cannot parse `z00`: invalid digit found in string");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole of `text`, starting at line 1, column 1.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            text,
+        }
+    }
+
+    fn cursor(&self) -> Cursor {
+        Cursor {
+            line: self.line,
+            column: self.column,
+            offset: 0,
+        }
+    }
+
+    /// The part of the text in the byte range `start..end`, positioned by
+    /// moving `cursor` on to `start`, which must not be behind it.
+    fn slice_from(&self, cursor: &mut Cursor, start: usize, end: usize) -> Span<'a> {
+        for c in self.text[cursor.offset..start].chars() {
+            if c == '\n' {
+                cursor.line += 1;
+                cursor.column = 1;
+            } else {
+                cursor.column += 1;
+            }
+        }
+        cursor.offset = start;
+        Span {
+            line: cursor.line,
+            column: cursor.column,
+            text: &self.text[start..end],
+        }
+    }
+
+    /// The part of the text in the byte range `start..end`.
+    fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        self.slice_from(&mut self.cursor(), start, end)
+    }
+
+    fn offset_of(&self, part: &str) -> usize {
+        part.as_ptr() as usize - self.text.as_ptr() as usize
+    }
+
+    /// A sub-slice of the text as a span, positioned where it lies.
+    fn span_of(&self, part: &'a str) -> Span<'a> {
+        let start = self.offset_of(part);
+        self.slice(start, start + part.len())
+    }
+
+    /// An error at the start of the span.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.span_of(self.text.trim())
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .parse()
+            .map_err(|e| self.error(format!("cannot parse `{}`: {}", self.text, e)))
+    }
+
+    /// The numbered lines of the span.
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        let mut cursor = self.cursor();
+        self.text.lines().map(move |line| {
+            let start = span.offset_of(line);
+            span.slice_from(&mut cursor, start, start + line.len())
+        })
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        let mut cursor = self.cursor();
+        self.text.split(separator).map(move |part| {
+            let start = span.offset_of(part);
+            span.slice_from(&mut cursor, start, start + part.len())
+        })
+    }
+
+    /// The text before and after the first `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let start = self
+            .text
+            .find(separator)
+            .ok_or_else(|| self.error(format!("expected `{}` in `{}`", separator, self.text)))?;
+        let end = start + separator.len();
+        Ok((self.slice(0, start), self.slice(end, self.text.len())))
+    }
+
+    /// Splits `key: value`.
+    pub fn key_value(&self) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        self.split_once(": ")
+    }
+
+    /// Splits `a -> b`.
+    pub fn arrow(&self) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        self.split_once(" -> ")
+    }
+
+    /// Parses `a, b,c`, allowing spaces around the commas.
+    pub fn comma_list<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(",").map(|item| item.trim().parse()).collect()
+    }
+
+    /// Every integer in the text, whatever surrounds it. A `-` right before
+    /// the digits is a sign unless it follows another digit, so `X=-3` gives
+    /// -3 but `1-3` gives 1 and 3.
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut cursor = self.cursor();
+        let mut ints = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let mut start = i;
+            if start > 0
+                && bytes[start - 1] == b'-'
+                && (start < 2 || !bytes[start - 2].is_ascii_digit())
+            {
+                start -= 1;
+            }
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            ints.push(self.slice_from(&mut cursor, start, i).parse()?);
+        }
+        Ok(ints)
+    }
+
+    /// Exactly `N` integers, as with [`Span::ints`].
+    pub fn ints_n<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let ints = self.ints()?;
+        let found = ints.len();
+        ints.try_into().map_err(|_| {
+            self.error(format!(
+                "expected {} numbers in `{}`, found {}",
+                N, self.text, found
+            ))
+        })
+    }
+}
+
+/// How far into a span its pieces have been positioned, so that positioning
+/// them in order scans the text once rather than from the start each time.
+struct Cursor {
+    line: usize,
+    column: usize,
+    /// Byte offset into the span's text.
+    offset: usize,
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).lines()
+}
+
+/// The blocks of `input` separated by blank lines, each keeping the number
+/// of its first line. Runs of blank lines count as one separator, and lines
+/// of only whitespace count as blank.
+pub fn blocks(input: &str) -> Vec<Span<'_>> {
+    let all = Span::new(input);
+    let mut blocks = Vec::new();
+    // The first line of the block so far, and where its last line ends.
+    let mut current: Option<(Span, usize)> = None;
+    let block = |(first, end): (Span<'_>, usize)| Span {
+        text: &input[all.offset_of(first.text)..end],
+        ..first
+    };
+    for line in all.lines() {
+        if line.text.trim().is_empty() {
+            blocks.extend(current.take().map(block));
+        } else {
+            let end = all.offset_of(line.text) + line.text.len();
+            current = Some((current.map_or(line, |(first, _)| first), end));
+        }
+    }
+    blocks.extend(current.map(block));
+    blocks
+}
+
+/// Exactly `N` blocks, as with [`blocks`].
+pub fn blocks_n<const N: usize>(input: &str) -> Result<[Span<'_>; N], ParseError> {
+    let blocks = blocks(input);
+    let found = blocks.len();
+    blocks.try_into().map_err(|_| {
+        Span::new(input).error(format!(
+            "expected {} blocks separated by blank lines, found {}",
+            N, found
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let line = Span::new("p=0,4 v=3,-3");
+        assert_eq!(line.ints::<i32>().unwrap(), vec![0, 4, 3, -3]);
+        let [x, y] = Span::new("Prize: X=-8400, Y=5400")
+            .ints_n::<i64, 2>()
+            .unwrap();
+        assert_eq!((x, y), (-8400, 5400));
+        assert_eq!(Span::new("1-3 a-b").ints::<i32>().unwrap(), vec![1, 3]);
+        assert_eq!(
+            Span::new("x 300").ints::<u8>().unwrap_err(),
+            ParseError {
+                line: 1,
+                column: 3,
+                message: String::from("cannot parse `300`: number too large to fit in target type"),
+            }
+        );
+        let error = Span::new("1 2 3").ints_n::<u8, 2>().unwrap_err();
+        assert_eq!(error.message, "expected 2 numbers in `1 2 3`, found 3");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "47|53\n97|13\n\n\n75,47,61\n  \n97,61\r\n61,13\n";
+        let blocks = blocks(input);
+        assert_eq!(
+            blocks.iter().map(|b| (b.line, b.text)).collect::<Vec<_>>(),
+            vec![(1, "47|53\n97|13"), (5, "75,47,61"), (7, "97,61\r\n61,13")]
+        );
+        let lines = blocks[2].lines().collect::<Vec<_>>();
+        assert_eq!((lines[1].line, lines[1].text), (8, "61,13"));
+        assert_eq!(lines[1].comma_list::<u8>().unwrap(), vec![61, 13]);
+        assert_eq!(
+            lines[1].split_once("|").unwrap_err().to_string(),
+            "line 8, column 1: expected `|` in `61,13`"
+        );
+        assert!(blocks_n::<3>(input).is_ok());
+        assert_eq!(
+            blocks_n::<2>(input).unwrap_err().message,
+            "expected 2 blocks separated by blank lines, found 3"
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        let input = "x00: 1\ny01: 2\n\nx00 AND y01 -> z00\n";
+        let [wires, gates] = blocks_n(input).unwrap();
+        let (key, value) = wires.lines().nth(1).unwrap().key_value().unwrap();
+        assert_eq!((key.text, value.parse::<u8>().unwrap()), ("y01", 2));
+        let (gate, output) = gates.lines().next().unwrap().arrow().unwrap();
+        assert_eq!(
+            (gate.text, output.line, output.column),
+            ("x00 AND y01", 4, 16)
+        );
+
+        let list = Span::new("75, 47 ,x1");
+        assert_eq!(
+            list.comma_list::<u32>().unwrap_err().to_string(),
+            "line 1, column 9: cannot parse `x1`: invalid digit found in string"
+        );
+        assert_eq!(
+            Span::new("r, wr, b").comma_list::<String>().unwrap(),
+            vec!["r", "wr", "b"]
+        );
+        let pieces = Span::new("a;bé\nc;d").split(";").collect::<Vec<_>>();
+        assert_eq!(
+            pieces
+                .iter()
+                .map(|p| (p.line, p.column))
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (1, 3), (2, 3)]
+        );
+        let error = Span::new("1 2\n3 999").ints::<u8>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
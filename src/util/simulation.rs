@@ -0,0 +1,224 @@
+use std::hash::Hash;
+
+/// A state that advances one step at a time, such as a guard on patrol or
+/// robots in a room.
+///
+/// Implementing `key` and `step` gives cycle detection, running until a
+/// condition holds, and jumping to a far step through a detected cycle.
+/// The key only has to tell states apart, so it can leave out anything that
+/// never changes.
+pub trait Simulation {
+    type Key: Hash + Eq;
+
+    fn key(&self) -> Self::Key;
+
+    /// Advances by one step, or returns `false` without changing anything
+    /// when the simulation has ended, e.g. the guard walked off the map.
+    fn step(&mut self) -> bool;
+
+    /// Takes up to `n` steps, returning how many were taken.
+    fn advance(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.step()).count()
+    }
+
+    /// Steps until `done` holds, checking the current state first, and
+    /// returns the number of steps taken; `None` if the simulation ended
+    /// first. Never returns if it cycles without `done` ever holding.
+    fn run_until<F: FnMut(&Self) -> bool>(&mut self, mut done: F) -> Option<usize> {
+        let mut steps = 0;
+        while !done(self) {
+            if !self.step() {
+                return None;
+            }
+            steps += 1;
+        }
+        Some(steps)
+    }
+
+    /// Finds the cycle the simulation ends up in with Brent's algorithm;
+    /// `None` if it ends instead. Never returns if it neither ends nor
+    /// repeats.
+    fn find_cycle(&self) -> Option<Cycle>
+    where
+        Self: Clone,
+    {
+        match brent(self, usize::MAX) {
+            Brent::Cycle(cycle) => Some(cycle),
+            Brent::Ended | Brent::Reached(_) => None,
+        }
+    }
+
+    /// The state after `n` steps, skipping whole turns of a cycle once one is
+    /// found, so `n` can be far larger than the number of distinct states.
+    /// `None` if the simulation ends before step `n`.
+    fn state_at(&self, n: usize) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        match brent(self, n) {
+            Brent::Reached(state) => Some(state),
+            Brent::Ended => None,
+            Brent::Cycle(cycle) => {
+                let mut state = self.clone();
+                state.advance(cycle.equivalent(n));
+                Some(state)
+            }
+        }
+    }
+}
+
+/// The states from step `start` on repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step whose state is the same as that of step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+enum Brent<S> {
+    Cycle(Cycle),
+    Ended,
+    /// Reached step `limit` before finding a cycle.
+    Reached(S),
+}
+
+fn brent<S: Simulation + Clone>(initial: &S, limit: usize) -> Brent<S> {
+    // Find the cycle length: the hare runs ahead while the tortoise waits
+    // at the last power of two.
+    let mut hare = initial.clone();
+    let mut tortoise = hare.key();
+    let (mut power, mut length, mut steps) = (1, 0, 0);
+    loop {
+        if steps == limit {
+            return Brent::Reached(hare);
+        }
+        if !hare.step() {
+            return Brent::Ended;
+        }
+        steps += 1;
+        length += 1;
+        if hare.key() == tortoise {
+            break;
+        }
+        if length == power {
+            tortoise = hare.key();
+            power *= 2;
+            length = 0;
+        }
+    }
+
+    // Find where it starts: two walkers `length` steps apart meet there.
+    let mut behind = initial.clone();
+    let mut ahead = initial.clone();
+    ahead.advance(length);
+    let mut start = 0;
+    while behind.key() != ahead.key() {
+        behind.step();
+        ahead.step();
+        start += 1;
+    }
+    Brent::Cycle(Cycle { start, length })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to `end`, then wraps back to `back`.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Counter {
+        value: usize,
+        end: usize,
+        back: usize,
+        stop: Option<usize>,
+    }
+
+    impl Simulation for Counter {
+        type Key = usize;
+
+        fn key(&self) -> usize {
+            self.value
+        }
+
+        fn step(&mut self) -> bool {
+            if self.stop == Some(self.value) {
+                return false;
+            }
+            self.value = if self.value == self.end {
+                self.back
+            } else {
+                self.value + 1
+            };
+            true
+        }
+    }
+
+    fn counter(end: usize, back: usize, stop: Option<usize>) -> Counter {
+        Counter {
+            value: 0,
+            end,
+            back,
+            stop,
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = counter(9, 3, None).find_cycle().unwrap();
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                length: 7
+            }
+        );
+        assert_eq!(cycle.equivalent(2), 2);
+        assert_eq!(cycle.equivalent(10), 3);
+        assert_eq!(cycle.equivalent(1_000_000_000), 3 + (1_000_000_000 - 3) % 7);
+        assert_eq!(
+            counter(0, 0, None).find_cycle(),
+            Some(Cycle {
+                start: 0,
+                length: 1
+            })
+        );
+        assert_eq!(counter(9, 3, Some(5)).find_cycle(), None);
+    }
+
+    #[test]
+    fn test_state_at() {
+        let sim = counter(9, 3, None);
+        for n in [0, 5, 9, 10, 100] {
+            let mut direct = sim.clone();
+            direct.advance(n);
+            assert_eq!(sim.state_at(n).unwrap(), direct, "step {}", n);
+        }
+        assert_eq!(
+            sim.state_at(1_000_000_007).unwrap().value,
+            3 + 1_000_000_004 % 7
+        );
+        assert_eq!(counter(9, 3, Some(5)).state_at(6), None);
+        assert_eq!(counter(9, 3, Some(5)).state_at(5).unwrap().value, 5);
+    }
+
+    #[test]
+    fn test_run_until() {
+        let mut sim = counter(9, 3, None);
+        assert_eq!(sim.run_until(|c| c.value == 7), Some(7));
+        assert_eq!(sim.run_until(|c| c.value == 7), Some(0));
+        assert_eq!(sim.run_until(|c| c.value == 4), Some(4));
+        let mut sim = counter(9, 3, Some(5));
+        assert_eq!(sim.run_until(|c| c.value == 7), None);
+        assert_eq!(sim.advance(10), 0);
+    }
+}
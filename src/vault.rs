@@ -0,0 +1,248 @@
+use anyhow::*;
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Where the team passphrase comes from: the environment first, then the
+/// first line of a gitignored file in the repository root.
+pub const PASSPHRASE_ENV: &str = "AOC_PASSPHRASE";
+pub const PASSPHRASE_FILE: &str = ".aoc-passphrase";
+
+/// The directories whose files may be stored encrypted.
+pub const DIRS: [&str; 2] = ["input", "answers"];
+
+/// Start of every sealed file, also authenticated with the contents.
+const MAGIC: &[u8] = b"aoc-vault-1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// The encrypted copy of `path`: `input/2024/16.txt.enc` for
+/// `input/2024/16.txt`.
+pub fn sealed_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".enc");
+    PathBuf::from(name)
+}
+
+fn is_sealed(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "enc")
+}
+
+/// The team passphrase, if one is set.
+pub fn passphrase() -> Result<Option<String>> {
+    if let Result::Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        if !passphrase.is_empty() {
+            return Ok(Some(passphrase));
+        }
+    }
+    match fs::read_to_string(PASSPHRASE_FILE) {
+        Result::Ok(text) => Ok(text
+            .lines()
+            .next()
+            .filter(|line| !line.is_empty())
+            .map(String::from)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("cannot read {}", PASSPHRASE_FILE)),
+    }
+}
+
+fn required_passphrase() -> Result<String> {
+    passphrase()?.ok_or_else(|| {
+        anyhow!(
+            "no passphrase: set {} or write it to {}",
+            PASSPHRASE_ENV,
+            PASSPHRASE_FILE
+        )
+    })
+}
+
+fn key(passphrase: &str, salt: &[u8]) -> Result<Key> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("cannot derive key: {}", e))?;
+    Ok(key)
+}
+
+/// Encrypts `plain` with XChaCha20-Poly1305 under a key derived from
+/// `passphrase` with Argon2id, using a fresh salt and nonce.
+pub fn seal(plain: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: plain,
+        aad: MAGIC,
+    };
+    let ciphertext = XChaCha20Poly1305::new(&key(passphrase, &salt)?)
+        .encrypt(&nonce, payload)
+        .map_err(|_| anyhow!("cannot encrypt"))?;
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+/// Decrypts what `seal` produced, failing if the passphrase is wrong or the
+/// file was tampered with.
+pub fn open(sealed: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let header = sealed
+        .strip_prefix(MAGIC)
+        .filter(|rest| rest.len() >= SALT_LEN + NONCE_LEN)
+        .ok_or_else(|| anyhow!("not an encrypted file"))?;
+    let (salt, rest) = header.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let payload = Payload {
+        msg: ciphertext,
+        aad: MAGIC,
+    };
+    XChaCha20Poly1305::new(&key(passphrase, salt)?)
+        .decrypt(XNonce::from_slice(nonce), payload)
+        .map_err(|_| anyhow!("wrong passphrase or corrupted file"))
+}
+
+/// Reads `path`, or decrypts its sealed copy when only that one exists.
+/// `None` means there is neither.
+pub fn read(path: &Path) -> Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Result::Ok(bytes) => return Ok(Some(bytes)),
+        Err(e) if e.kind() == ErrorKind::NotFound => (),
+        Err(e) => return Err(e).with_context(|| format!("cannot read {}", path.display())),
+    }
+    let sealed_path = sealed_path(path);
+    let sealed = match fs::read(&sealed_path) {
+        Result::Ok(sealed) => sealed,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("cannot read {}", sealed_path.display())),
+    };
+    let plain = required_passphrase()
+        .and_then(|passphrase| open(&sealed, &passphrase))
+        .with_context(|| format!("cannot decrypt {}", sealed_path.display()))?;
+    Ok(Some(plain))
+}
+
+/// Writes `path`, and its sealed copy as well when a passphrase is set.
+pub fn write(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, bytes).with_context(|| format!("cannot write {}", path.display()))?;
+    if let Some(passphrase) = passphrase()? {
+        update(&sealed_path(path), bytes, &passphrase)?;
+    }
+    Ok(())
+}
+
+/// Seals `plain` into `sealed_path` unless it already holds the same text
+/// under the same passphrase, since every seal differs and would show up as
+/// a change in git. Returns whether the file was written.
+fn update(sealed_path: &Path, plain: &[u8], passphrase: &str) -> Result<bool> {
+    if let Result::Ok(sealed) = fs::read(sealed_path) {
+        if open(&sealed, passphrase).is_ok_and(|current| current == plain) {
+            return Ok(false);
+        }
+    }
+    replace(sealed_path, &seal(plain, passphrase)?)?;
+    Ok(true)
+}
+
+/// Writes through a temporary file so an interrupted run never leaves half
+/// a sealed file behind.
+fn replace(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, bytes).with_context(|| format!("cannot write {}", path.display()))?;
+    fs::rename(&tmp, path).with_context(|| format!("cannot write {}", path.display()))
+}
+
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .map_while(Result::ok)
+    {
+        let path = entry.path();
+        if path.is_dir() {
+            walk(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+/// Every file under `input/` and `answers/`, sorted.
+fn files() -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for dir in DIRS {
+        walk(Path::new(dir), &mut files);
+    }
+    files.sort();
+    files
+}
+
+/// Seals every plain `.txt` file under `input/` and `answers/`, returning
+/// the sealed copies that were new or out of date.
+pub fn encrypt_all() -> Result<Vec<PathBuf>> {
+    let passphrase = required_passphrase()?;
+    let mut written = Vec::new();
+    for path in files() {
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let plain = fs::read(&path).with_context(|| format!("cannot read {}", path.display()))?;
+        let sealed_path = sealed_path(&path);
+        if update(&sealed_path, &plain, &passphrase)? {
+            written.push(sealed_path);
+        }
+    }
+    Ok(written)
+}
+
+/// Re-encrypts every sealed file under `input/` and `answers/` from `old`
+/// to `new`. All of them are decrypted before any is written, so a wrong
+/// `old` passphrase leaves everything as it was.
+pub fn rekey(old: &str, new: &str) -> Result<Vec<PathBuf>> {
+    let opened = files()
+        .into_iter()
+        .filter(|path| is_sealed(path))
+        .map(|path| {
+            let sealed =
+                fs::read(&path).with_context(|| format!("cannot read {}", path.display()))?;
+            let plain =
+                open(&sealed, old).with_context(|| format!("cannot decrypt {}", path.display()))?;
+            Ok((path, plain))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    for (path, plain) in &opened {
+        replace(path, &seal(plain, new)?)?;
+    }
+    Ok(opened.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open() {
+        let sealed = seal(b"1: 11048\n", "hunter2").unwrap();
+        assert!(sealed.starts_with(MAGIC));
+        assert_eq!(open(&sealed, "hunter2").unwrap(), b"1: 11048\n");
+        assert_ne!(seal(b"1: 11048\n", "hunter2").unwrap(), sealed);
+        assert!(open(&sealed, "hunter3").is_err());
+
+        let mut tampered = sealed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(open(&tampered, "hunter2").is_err());
+        assert!(open(b"1: 11048\n", "hunter2").is_err());
+    }
+
+    #[test]
+    fn test_sealed_path() {
+        let path = sealed_path(Path::new("input/2024/16.txt"));
+        assert_eq!(path, PathBuf::from("input/2024/16.txt.enc"));
+        assert!(is_sealed(&path));
+        assert!(!is_sealed(Path::new("input/2024/16.txt")));
+    }
+}
@@ -0,0 +1,480 @@
+use crate::registry::DayId;
+use crate::util::grid::{Grid, Point};
+use anyhow::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tracing::{info, warn};
+
+mod player;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0x10, 0x10, 0x18];
+pub const WHITE: Rgb = [0xf0, 0xf0, 0xf0];
+pub const GREY: Rgb = [0x70, 0x70, 0x78];
+pub const RED: Rgb = [0xe0, 0x30, 0x30];
+pub const GREEN: Rgb = [0x30, 0xb0, 0x40];
+pub const BLUE: Rgb = [0x30, 0x60, 0xe0];
+pub const YELLOW: Rgb = [0xf0, 0xd0, 0x30];
+pub const ORANGE: Rgb = [0xf0, 0x90, 0x20];
+
+/// How recorded frames are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Export {
+    /// One looping animation, `viz/<year>/<day>-<name>.gif`.
+    Gif,
+    /// One image per frame in `viz/<year>/<day>-<name>/`.
+    Png,
+    Ppm,
+    /// Plays the frames in the terminal instead of writing files.
+    Play,
+    /// Only the final frame, `viz/<year>/<day>-<name>.png`, as used by the
+    /// report.
+    Last,
+}
+
+impl FromStr for Export {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "gif" => Ok(Export::Gif),
+            "png" => Ok(Export::Png),
+            "ppm" => Ok(Export::Ppm),
+            "play" => Ok(Export::Play),
+            "last" => Ok(Export::Last),
+            _ => bail!(
+                "unknown export `{}`, expected one of gif, png, ppm, play, last",
+                s
+            ),
+        }
+    }
+}
+
+static TARGET: OnceLock<(PathBuf, Export)> = OnceLock::new();
+static FPS: OnceLock<u32> = OnceLock::new();
+
+/// Turns recording on for the rest of the process; until then every
+/// [`Recorder`] ignores its frames. Called by the runner for `--record`,
+/// with the frame rate of `--fps`, if any, for playback.
+pub fn enable(id: DayId, export: Export, fps: Option<u32>) {
+    let _ = TARGET.set((id.viz_path(), export));
+    if let Some(fps) = fps {
+        let _ = FPS.set(fps);
+    }
+}
+
+static SOLVING: AtomicBool = AtomicBool::new(false);
+
+/// Runs `f` with recording on, if it is enabled at all. The runner wraps the
+/// solvers of the real input in it, so the example checks that a day runs
+/// first never record over the input's files under the same names.
+pub(crate) fn solving<T>(f: impl FnOnce() -> T) -> T {
+    let was = SOLVING.swap(true, Ordering::Relaxed);
+    let result = f();
+    SOLVING.store(was, Ordering::Relaxed);
+    result
+}
+
+/// Maps cell values to colours, with a fallback for anything unlisted.
+#[derive(Debug, Clone)]
+pub struct Palette<T> {
+    colors: HashMap<T, Rgb>,
+    fallback: Rgb,
+}
+
+impl<T: Hash + Eq> Palette<T> {
+    pub fn new(fallback: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            fallback,
+        }
+    }
+
+    pub fn with(mut self, value: T, color: Rgb) -> Self {
+        self.colors.insert(value, color);
+        self
+    }
+
+    pub fn color(&self, value: &T) -> Rgb {
+        self.colors.get(value).copied().unwrap_or(self.fallback)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Frame {
+    width: usize,
+    height: usize,
+    /// Indices into the recorder's colour table.
+    pixels: Vec<u8>,
+}
+
+/// Collects frames of a grid simulation and exports them as an animation.
+///
+/// ```ignore
+/// let palette = Palette::new(BLACK).with(b'#', GREY).with(b'O', YELLOW);
+/// let mut recorder = Recorder::new("part1", palette).every(10);
+/// for m in moves {
+///     // ...
+///     recorder.record(&grid);
+/// }
+/// recorder.snapshot(&grid);
+/// recorder.finish()?;
+/// ```
+///
+/// Recording is off unless the day runs with `--record gif|png|ppm|play|last`
+/// (or `--play`), and then only while the real input is solved. A disabled
+/// recorder does no work at all.
+pub struct Recorder<T> {
+    name: String,
+    palette: Palette<T>,
+    target: Option<(PathBuf, Export)>,
+    every: usize,
+    scale: usize,
+    delay: u16,
+    colors: Vec<Rgb>,
+    index: HashMap<Rgb, u8>,
+    frames: Vec<Frame>,
+    count: usize,
+}
+
+impl<T: Hash + Eq> Recorder<T> {
+    /// A recorder writing to `viz/<year>/<day>-<name>` when recording is on.
+    pub fn new(name: &str, palette: Palette<T>) -> Self {
+        let target = TARGET
+            .get()
+            .filter(|_| SOLVING.load(Ordering::Relaxed))
+            .map(|(stem, export)| (with_suffix(stem, name), *export));
+        Self::with_target(name, palette, target)
+    }
+
+    fn with_target(name: &str, palette: Palette<T>, target: Option<(PathBuf, Export)>) -> Self {
+        Self {
+            name: name.to_string(),
+            palette,
+            target,
+            every: 1,
+            scale: 4,
+            delay: 5,
+            colors: Vec::new(),
+            index: HashMap::new(),
+            frames: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Keeps one frame in `n` of those passed to [`Recorder::record`]; the
+    /// others are not even drawn.
+    pub fn every(mut self, n: usize) -> Self {
+        self.every = n.max(1);
+        self
+    }
+
+    /// Side of the square each cell is drawn as, in pixels (4 by default).
+    pub fn scale(mut self, pixels: usize) -> Self {
+        self.scale = pixels.max(1);
+        self
+    }
+
+    /// Time each frame is shown, in hundredths of a second (5 by default).
+    pub fn delay(mut self, centiseconds: u16) -> Self {
+        self.delay = centiseconds;
+        self
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.target.is_some()
+    }
+
+    pub fn record(&mut self, grid: &Grid<T>)
+    where
+        T: Default + Copy + PartialEq,
+    {
+        self.record_with(grid, []);
+    }
+
+    /// Records `grid` with some cells painted over, e.g. a guard or a path
+    /// that is not part of the grid itself.
+    pub fn record_with<I>(&mut self, grid: &Grid<T>, marks: I)
+    where
+        T: Default + Copy + PartialEq,
+        I: IntoIterator<Item = (Point, Rgb)>,
+    {
+        self.count += 1;
+        if (self.count - 1).is_multiple_of(self.every) {
+            self.snapshot_with(grid, marks);
+        }
+    }
+
+    /// Records a frame regardless of [`Recorder::every`], e.g. the final state.
+    pub fn snapshot(&mut self, grid: &Grid<T>)
+    where
+        T: Default + Copy + PartialEq,
+    {
+        self.snapshot_with(grid, []);
+    }
+
+    pub fn snapshot_with<I>(&mut self, grid: &Grid<T>, marks: I)
+    where
+        T: Default + Copy + PartialEq,
+        I: IntoIterator<Item = (Point, Rgb)>,
+    {
+        if !self.is_recording() {
+            return;
+        }
+        let mut colors = grid
+            .array
+            .iter()
+            .map(|v| self.palette.color(v))
+            .collect::<Vec<_>>();
+        for ((y, x), color) in marks {
+            colors[y * grid.width + x] = color;
+        }
+        let pixels = colors.into_iter().map(|c| self.color_index(c)).collect();
+        let frame = Frame {
+            width: grid.width,
+            height: grid.height,
+            pixels,
+        };
+        if let Some(first) = self.frames.first() {
+            assert_eq!(
+                (first.width, first.height),
+                (frame.width, frame.height),
+                "frames of `{}` change size",
+                self.name
+            );
+        }
+        if self
+            .target
+            .as_ref()
+            .is_some_and(|(_, e)| *e == Export::Last)
+        {
+            self.frames.clear();
+        }
+        self.frames.push(frame);
+    }
+
+    fn color_index(&mut self, color: Rgb) -> u8 {
+        if let Some(&i) = self.index.get(&color) {
+            return i;
+        }
+        if self.colors.len() == 256 {
+            warn!(
+                name = self.name,
+                ?color,
+                "more than 256 colours, reusing the first"
+            );
+            return 0;
+        }
+        let i = self.colors.len() as u8;
+        self.colors.push(color);
+        self.index.insert(color, i);
+        i
+    }
+
+    /// Writes the recorded frames out, if recording is on.
+    pub fn finish(mut self) -> Result<()> {
+        let Some((path, export)) = self.target.take() else {
+            return Ok(());
+        };
+        if self.frames.is_empty() {
+            return Ok(());
+        }
+        let path = match export {
+            Export::Gif => {
+                let path = path.with_extension("gif");
+                self.write_gif(&path)?;
+                path
+            }
+            Export::Png | Export::Ppm => {
+                self.write_sequence(&path, export)?;
+                path
+            }
+            Export::Last => {
+                let path = path.with_extension("png");
+                let frame = &self.frames[self.frames.len() - 1];
+                self.write_png(&path, frame)?;
+                path
+            }
+            Export::Play => {
+                let delay = match FPS.get() {
+                    Some(&fps) => Duration::from_secs(1) / fps,
+                    None => Duration::from_millis(10 * u64::from(self.delay.max(1))),
+                };
+                return player::play(&self.name, &self.frames, &self.colors, delay);
+            }
+        };
+        info!(frames = self.frames.len(), path = %path.display(), "recorded");
+        Ok(())
+    }
+
+    fn scaled(&self, frame: &Frame) -> Vec<u8> {
+        let s = self.scale;
+        let mut out = Vec::with_capacity(frame.pixels.len() * s * s);
+        for row in frame.pixels.chunks(frame.width) {
+            let line = row
+                .iter()
+                .flat_map(|&p| std::iter::repeat_n(p, s))
+                .collect::<Vec<_>>();
+            for _ in 0..s {
+                out.extend_from_slice(&line);
+            }
+        }
+        out
+    }
+
+    fn size(&self) -> (usize, usize) {
+        let frame = &self.frames[0];
+        (frame.width * self.scale, frame.height * self.scale)
+    }
+
+    fn write_gif(&self, path: &Path) -> Result<()> {
+        let (width, height) = self.size();
+        let (width, height) = (
+            u16::try_from(width).context("animation too wide for a GIF")?,
+            u16::try_from(height).context("animation too tall for a GIF")?,
+        );
+        let file = create(path)?;
+        let mut encoder = gif::Encoder::new(file, width, height, self.colors.as_flattened())?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in &self.frames {
+            let mut frame =
+                gif::Frame::from_indexed_pixels(width, height, self.scaled(frame), None);
+            frame.delay = self.delay;
+            encoder.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+
+    fn write_png(&self, path: &Path, frame: &Frame) -> Result<()> {
+        let (width, height) = self.size();
+        let file = create(path)?;
+        let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(self.colors.as_flattened());
+        encoder
+            .write_header()?
+            .write_image_data(&self.scaled(frame))?;
+        Ok(())
+    }
+
+    fn write_sequence(&self, dir: &Path, export: Export) -> Result<()> {
+        std::fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+        let (width, height) = self.size();
+        for (i, frame) in self.frames.iter().enumerate() {
+            match export {
+                Export::Png => self.write_png(&dir.join(format!("{:05}.png", i)), frame)?,
+                _ => {
+                    let mut file = create(&dir.join(format!("{:05}.ppm", i)))?;
+                    write!(file, "P6\n{} {}\n255\n", width, height)?;
+                    for p in self.scaled(frame) {
+                        file.write_all(&self.colors[p as usize])?;
+                    }
+                    file.flush()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn with_suffix(stem: &Path, name: &str) -> PathBuf {
+    let mut file = stem.file_name().unwrap_or_default().to_os_string();
+    file.push("-");
+    file.push(name);
+    stem.with_file_name(file)
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    }
+    let file = File::create(path).with_context(|| format!("cannot create {}", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(cells: &str) -> Grid<u8> {
+        Grid::<u8>::from_reader(cells.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::new(BLACK).with(b'#', WHITE);
+        assert_eq!(palette.color(&b'#'), WHITE);
+        assert_eq!(palette.color(&b'.'), BLACK);
+    }
+
+    #[test]
+    fn test_recorder() {
+        let palette = Palette::new(BLACK).with(b'#', WHITE);
+        let mut recorder = Recorder::new("test", palette.clone());
+        recorder.record(&grid("#.\n"));
+        assert!(recorder.frames.is_empty());
+
+        let target = Some((PathBuf::from("unused"), Export::Gif));
+        let mut recorder = Recorder::with_target("test", palette, target)
+            .every(2)
+            .scale(2);
+        recorder.record(&grid("#.\n"));
+        recorder.record_with(&grid("#.\n"), [((0, 1), RED)]);
+        recorder.record(&grid(".#\n"));
+        recorder.snapshot_with(&grid("..\n"), [((0, 0), RED)]);
+        assert_eq!(recorder.colors, [WHITE, BLACK, RED]);
+        assert_eq!(recorder.frames.len(), 3);
+        assert_eq!(recorder.frames[2].pixels, [2, 1]);
+        assert_eq!(
+            recorder.scaled(&recorder.frames[1]),
+            [1, 1, 0, 0, 1, 1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        let dir = std::env::temp_dir().join(format!("viz-test-{}", std::process::id()));
+        let palette = Palette::new(BLACK).with(b'#', WHITE);
+        for (export, file) in [
+            (Export::Gif, "a-test.gif"),
+            (Export::Png, "a-test/00000.png"),
+            (Export::Ppm, "a-test/00000.ppm"),
+            (Export::Last, "a-test.png"),
+        ] {
+            let target = Some((dir.join("a-test"), export));
+            let mut recorder = Recorder::with_target("test", palette.clone(), target).scale(1);
+            recorder.record(&grid("#.\n"));
+            recorder.record(&grid(".#\n"));
+            assert_eq!(
+                recorder.frames.len(),
+                if export == Export::Last { 1 } else { 2 }
+            );
+            recorder.finish().unwrap();
+            let bytes = std::fs::read(dir.join(file)).unwrap();
+            match export {
+                Export::Gif => assert!(bytes.starts_with(b"GIF89a")),
+                Export::Png | Export::Last => assert!(bytes.starts_with(b"\x89PNG")),
+                Export::Ppm => assert_eq!(bytes, b"P6\n2 1\n255\n\xf0\xf0\xf0\x10\x10\x18"),
+                Export::Play => unreachable!(),
+            }
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_with_suffix() {
+        assert_eq!(
+            with_suffix(Path::new("viz/2024/16"), "part2"),
+            PathBuf::from("viz/2024/16-part2")
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use super::{Frame, Rgb};
+use anyhow::*;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, terminal, ExecutableCommand};
+use std::fmt::Write as _;
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+use tracing::warn;
+
+const HELP: &str = "space pause  ←/→ step  home/end first/last  <n>⏎ jump  +/- speed  q quit";
+
+/// Where the player is in its frames, driven by key presses and the clock.
+#[derive(Debug, Clone, PartialEq)]
+struct Playback {
+    frame: usize,
+    frames: usize,
+    playing: bool,
+    delay: Duration,
+    /// Digits typed so far for a jump.
+    jump: String,
+}
+
+impl Playback {
+    fn new(frames: usize, delay: Duration) -> Self {
+        Self {
+            frame: 0,
+            frames,
+            playing: true,
+            delay,
+            jump: String::new(),
+        }
+    }
+
+    /// Advances one frame while playing, pausing on the last one.
+    fn tick(&mut self) {
+        if self.playing && self.frame + 1 < self.frames {
+            self.frame += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+
+    /// Applies a key press; `false` means quit.
+    fn key(&mut self, key: KeyEvent) -> bool {
+        let last = self.frames - 1;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(c @ '0'..='9') => {
+                self.jump.push(c);
+                return true;
+            }
+            KeyCode::Backspace => {
+                self.jump.pop();
+                return true;
+            }
+            KeyCode::Enter => {
+                if let Result::Ok(n) = self.jump.parse::<usize>() {
+                    self.frame = n.min(last);
+                    self.playing = false;
+                }
+            }
+            KeyCode::Char(' ') => {
+                if self.frame == last {
+                    self.frame = 0;
+                }
+                self.playing = !self.playing;
+            }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('.') => {
+                self.frame = (self.frame + 1).min(last);
+                self.playing = false;
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char(',') => {
+                self.frame = self.frame.saturating_sub(1);
+                self.playing = false;
+            }
+            KeyCode::Home | KeyCode::Char('g') => self.frame = 0,
+            KeyCode::End | KeyCode::Char('G') => self.frame = last,
+            KeyCode::Char('+') => self.delay = (self.delay / 2).max(Duration::from_millis(1)),
+            KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(2)),
+            _ => (),
+        }
+        self.jump.clear();
+        true
+    }
+
+    fn status(&self, name: &str) -> String {
+        let state = if self.playing { "playing" } else { "paused" };
+        let jump = if self.jump.is_empty() {
+            String::new()
+        } else {
+            format!("  jump to {}_", self.jump)
+        };
+        format!(
+            "{}  frame {}/{}  {}  {:.0} fps{}",
+            name,
+            self.frame,
+            self.frames - 1,
+            state,
+            1.0 / self.delay.as_secs_f64(),
+            jump
+        )
+    }
+}
+
+/// Draws a frame with `▀` half blocks, two cells per character, cropped to
+/// `columns` x `rows` characters.
+fn render(frame: &Frame, colors: &[Rgb], columns: usize, rows: usize) -> String {
+    let color = |y: usize, x: usize| colors[frame.pixels[y * frame.width + x] as usize];
+    let mut out = String::new();
+    for y in (0..frame.height).step_by(2).take(rows) {
+        let mut pen = None;
+        for x in 0..frame.width.min(columns) {
+            let top = color(y, x);
+            let bottom = (y + 1 < frame.height).then(|| color(y + 1, x));
+            if pen != Some((top, bottom)) {
+                let [r, g, b] = top;
+                let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+                match bottom {
+                    Some([r, g, b]) => {
+                        let _ = write!(out, "\x1b[48;2;{};{};{}m", r, g, b);
+                    }
+                    None => out.push_str("\x1b[49m"),
+                }
+                pen = Some((top, bottom));
+            }
+            out.push('▀');
+        }
+        out.push_str("\x1b[0m\x1b[K\r\n");
+    }
+    out
+}
+
+/// Puts the terminal back however the player exits.
+struct Screen;
+
+impl Screen {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        std::io::stderr()
+            .execute(terminal::EnterAlternateScreen)?
+            .execute(cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = std::io::stderr()
+            .execute(cursor::Show)
+            .and_then(|e| e.execute(terminal::LeaveAlternateScreen));
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Plays `frames` on the terminal until the user quits. Draws on stderr so
+/// the runner's output on stdout is left alone.
+pub(super) fn play(name: &str, frames: &[Frame], colors: &[Rgb], delay: Duration) -> Result<()> {
+    if !std::io::stderr().is_terminal() {
+        warn!(name, "not a terminal, skipping playback");
+        return Ok(());
+    }
+    let _screen = Screen::enter()?;
+    let mut stderr = std::io::stderr();
+    let mut playback = Playback::new(frames.len(), delay);
+    loop {
+        let (columns, rows) = terminal::size()?;
+        let (columns, rows) = (columns as usize, (rows as usize).saturating_sub(2));
+        let mut out = String::from("\x1b[H");
+        out += &render(&frames[playback.frame], colors, columns, rows);
+        out += &format!("{}\x1b[K\r\n{}\x1b[K", playback.status(name), HELP);
+        stderr.write_all(out.as_bytes())?;
+        stderr.flush()?;
+
+        let timeout = if playback.playing {
+            playback.delay
+        } else {
+            Duration::from_secs(60)
+        };
+        if event::poll(timeout)? {
+            match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release && !playback.key(key) => {
+                    return Ok(());
+                }
+                Event::Resize(..) => {
+                    stderr.execute(terminal::Clear(terminal::ClearType::All))?;
+                }
+                _ => (),
+            }
+        } else {
+            playback.tick();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(playback: &mut Playback, code: KeyCode) -> bool {
+        playback.key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_playback() {
+        let mut playback = Playback::new(20, Duration::from_millis(40));
+        playback.tick();
+        assert_eq!((playback.frame, playback.playing), (1, true));
+
+        press(&mut playback, KeyCode::Right);
+        assert_eq!((playback.frame, playback.playing), (2, false));
+        playback.tick();
+        assert_eq!(playback.frame, 2);
+        press(&mut playback, KeyCode::Left);
+        press(&mut playback, KeyCode::Left);
+        press(&mut playback, KeyCode::Left);
+        assert_eq!(playback.frame, 0);
+
+        for c in "15".chars() {
+            press(&mut playback, KeyCode::Char(c));
+        }
+        assert!(playback.status("test").contains("jump to 15_"));
+        press(&mut playback, KeyCode::Enter);
+        assert_eq!((playback.frame, playback.jump.as_str()), (15, ""));
+        press(&mut playback, KeyCode::End);
+        assert_eq!(playback.frame, 19);
+
+        press(&mut playback, KeyCode::Char(' '));
+        assert_eq!((playback.frame, playback.playing), (0, true));
+        press(&mut playback, KeyCode::Char('+'));
+        assert_eq!(playback.delay, Duration::from_millis(20));
+        assert!(!press(&mut playback, KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn test_render() {
+        let frame = Frame {
+            width: 2,
+            height: 3,
+            pixels: vec![0, 0, 0, 1, 1, 1],
+        };
+        let colors = [[1, 2, 3], [4, 5, 6]];
+        let out = render(&frame, &colors, 80, 24);
+        assert_eq!(
+            out,
+            "\x1b[38;2;1;2;3m\x1b[48;2;1;2;3m▀\x1b[38;2;1;2;3m\x1b[48;2;4;5;6m▀\x1b[0m\x1b[K\r\n\
+             \x1b[38;2;4;5;6m\x1b[49m▀▀\x1b[0m\x1b[K\r\n"
+        );
+        assert_eq!(render(&frame, &colors, 1, 1).matches('▀').count(), 1);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::registry::DayId;
+use crate::runner::{Record, Status};
+use crate::vault;
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Modification times of every file a day's answers depend on: the sources
+/// under `src/`, `Cargo.toml`, and the day's input and accepted answers,
+/// plain or sealed. The examples are the `TEST` consts in the day's source,
+/// so they are watched with the rest of `src/`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    pub fn take(id: DayId) -> Self {
+        let mut files = BTreeMap::new();
+        collect(Path::new("src"), &mut files);
+        for path in [id.input_path(), id.answers_path()] {
+            collect(&vault::sealed_path(&path), &mut files);
+            collect(&path, &mut files);
+        }
+        collect(Path::new("Cargo.toml"), &mut files);
+        Self(files)
+    }
+
+    /// Files that were added, removed or modified since `earlier`.
+    pub fn changes(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let mut changed = self
+            .0
+            .iter()
+            .filter(|(path, time)| earlier.0.get(*path) != Some(time))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.extend(
+            earlier
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .cloned(),
+        );
+        changed
+    }
+}
+
+fn collect(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Result::Ok(meta) = fs::metadata(path) else {
+        return;
+    };
+    if meta.is_dir() {
+        for entry in fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .map_while(Result::ok)
+        {
+            collect(&entry.path(), files);
+        }
+    } else if let Result::Ok(modified) = meta.modified() {
+        files.insert(path.to_owned(), modified);
+    }
+}
+
+/// How an answer moved between two runs of the same day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    New(String),
+    Same(String),
+    Changed { old: String, new: String },
+    Failed(String),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::New(answer) => write!(f, "{}", answer),
+            Change::Same(answer) => write!(f, "{} (unchanged)", answer),
+            Change::Changed { old, new } => write!(f, "{} -> {} (changed)", old, new),
+            Change::Failed(error) => write!(f, "error: {}", error),
+        }
+    }
+}
+
+/// Compares each part of `current` against the same part in `previous`.
+pub fn diff(previous: &[Record], current: &[Record]) -> Vec<(u8, Change)> {
+    current
+        .iter()
+        .map(|record| {
+            let old = previous
+                .iter()
+                .find(|r| r.part == record.part)
+                .and_then(|r| r.answer.clone());
+            let change = match (&record.answer, old) {
+                (Some(new), Some(old)) if *new == old => Change::Same(old),
+                (Some(new), Some(old)) => Change::Changed {
+                    old,
+                    new: new.clone(),
+                },
+                (Some(new), None) => Change::New(new.clone()),
+                (None, _) => Change::Failed(record.error.clone().unwrap_or_default()),
+            };
+            (record.part, change)
+        })
+        .collect()
+}
+
+/// Re-runs a day whenever one of its files changes, until interrupted.
+///
+/// `extra_args` are passed on to the day, e.g. `-v` for its log output.
+pub fn watch(id: DayId, release: bool, extra_args: &[&str], interval: Duration) -> Result<()> {
+    let mut snapshot = Snapshot::default();
+    let mut previous = Vec::new();
+    let mut run = 0;
+    loop {
+        let current = Snapshot::take(id);
+        let changes = current.changes(&snapshot);
+        snapshot = current;
+        if changes.is_empty() {
+            std::thread::sleep(interval);
+            continue;
+        }
+        run += 1;
+        if run > 1 {
+            let names = changes
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>();
+            println!("\nchanged: {}", names.join(", "));
+        }
+        println!("=== {} run #{} ===", id, run);
+        match id.run(release, extra_args) {
+            Result::Ok(records) => {
+                for ((part, change), record) in diff(&previous, &records).iter().zip(&records) {
+                    match (record.status, &record.expected) {
+                        (Status::Wrong, Some(expected)) => {
+                            println!("Part {}: {} [wrong, expected {}]", part, change, expected)
+                        }
+                        _ => println!("Part {}: {}", part, change),
+                    }
+                }
+                previous = records;
+            }
+            Err(e) => println!("{:#}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u8, answer: Option<&str>) -> Record {
+        Record {
+            year: 2024,
+            day: 1,
+            part,
+            answer: answer.map(String::from),
+            expected: None,
+            status: if answer.is_some() {
+                Status::Ok
+            } else {
+                Status::Error
+            },
+            input_hash: None,
+            parse_ms: None,
+            elapsed_ms: 0.,
+            serial_ms: None,
+            peak_kib: None,
+            error: answer.is_none().then(|| String::from("boom")),
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = [record(1, Some("11")), record(2, Some("31"))];
+        let current = [record(1, Some("11")), record(2, Some("32"))];
+        assert_eq!(
+            diff(&previous, &current),
+            vec![
+                (1, Change::Same(String::from("11"))),
+                (
+                    2,
+                    Change::Changed {
+                        old: String::from("31"),
+                        new: String::from("32")
+                    }
+                )
+            ]
+        );
+        assert_eq!(
+            diff(&[], &[record(1, Some("7")), record(2, None)]),
+            vec![
+                (1, Change::New(String::from("7"))),
+                (2, Change::Failed(String::from("boom")))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let before = Snapshot(BTreeMap::from([
+            (PathBuf::from("a"), t0),
+            (PathBuf::from("b"), t0),
+        ]));
+        let after = Snapshot(BTreeMap::from([
+            (PathBuf::from("a"), t1),
+            (PathBuf::from("c"), t0),
+        ]));
+        assert_eq!(
+            after.changes(&before),
+            vec![PathBuf::from("a"), PathBuf::from("c"), PathBuf::from("b")]
+        );
+        assert!(after.changes(&after).is_empty());
+    }
+}